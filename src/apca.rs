@@ -0,0 +1,135 @@
+use crate::{Channel, RGB};
+
+const MAIN_TRC: f32 = 2.4;
+const NORM_BG: f32 = 0.56;
+const NORM_TXT: f32 = 0.57;
+const REV_TXT: f32 = 0.62;
+const REV_BG: f32 = 0.65;
+const BLK_THRS: f32 = 0.022;
+const BLK_CLMP: f32 = 1.414;
+const SCALE: f32 = 1.14;
+const LO_OFFSET: f32 = 0.027;
+const LO_CLIP: f32 = 0.1;
+const DELTA_Y_MIN: f32 = 0.0005;
+
+/// The minimum font size in pixels for each font weight from 100 to 900, in
+/// rows of increasing contrast from Lc 0 in steps of 5. A size of zero marks
+/// contrast which is too low for any text.
+const FONT_SIZES: [[f32; 9]; 26] = [
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0; 9],
+    [0.0, 0.0, 0.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [0.0, 0.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [0.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.5, 14.0, 13.0, 12.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0],
+    [34.0, 22.5, 17.5, 13.5, 12.5, 11.5, 10.0, 16.0, 18.0],
+    [32.0, 21.0, 17.0, 13.0, 12.0, 11.0, 10.0, 16.0, 18.0],
+    [30.0, 20.0, 16.5, 12.5, 11.5, 10.5, 10.0, 16.0, 18.0],
+];
+
+/// The screen luminance estimate used by APCA, with the soft clamp applied
+/// to near black colors
+fn luminance<T>(color: RGB<T>) -> f32
+where
+    T: Channel,
+{
+    let [red, green, blue] =
+        [color.red, color.green, color.blue].map(|c| c.to_percent().powf(MAIN_TRC));
+    let y = 0.212_672_9 * red + 0.715_152_2 * green + 0.072_175 * blue;
+    if y > BLK_THRS {
+        y
+    } else {
+        y + (BLK_THRS - y).powf(BLK_CLMP)
+    }
+}
+
+/// Calculates the APCA lightness contrast (Lc) of text against a background,
+/// using the 0.0.98G-4g constants of the WCAG 3 draft. The result ranges from
+/// about 106 for black text on white to about -108 for white text on black,
+/// with negative values for light text on a dark background.
+/// ```
+/// use rgba_simple::{apca_contrast, RGB};
+///
+/// let gray = RGB::<u8>::new(136, 136, 136);
+/// let white = RGB::<u8>::new(255, 255, 255);
+/// assert!((apca_contrast(gray, white) - 63.06).abs() < 0.01);
+/// assert!((apca_contrast(white, gray) + 68.54).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn apca_contrast<T>(text: RGB<T>, background: RGB<T>) -> f32
+where
+    T: Channel,
+{
+    let text = luminance(text);
+    let background = luminance(background);
+    if (background - text).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+    let lc = if background > text {
+        let sapc = (background.powf(NORM_BG) - text.powf(NORM_TXT)) * SCALE;
+        if sapc < LO_CLIP {
+            0.0
+        } else {
+            sapc - LO_OFFSET
+        }
+    } else {
+        let sapc = (background.powf(REV_BG) - text.powf(REV_TXT)) * SCALE;
+        if sapc > -LO_CLIP {
+            0.0
+        } else {
+            sapc + LO_OFFSET
+        }
+    };
+    lc * 100.0
+}
+
+/// Looks up the minimum font size in pixels for text of the given weight
+/// with an APCA contrast of `lc`, of either polarity. The contrast is rounded
+/// down to the nearest step of 5 and the weight down to the nearest hundred,
+/// clamped between 100 and 900. Returns `None` when the contrast is too low
+/// for text of any size.
+#[must_use]
+pub fn apca_font_size(lc: f32, weight: u16) -> Option<f32> {
+    let row = ((lc.abs() / 5.0) as usize).min(FONT_SIZES.len() - 1);
+    let column = usize::from(weight.clamp(100, 900) / 100 - 1);
+    Some(FONT_SIZES[row][column]).filter(|size| *size > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polarity() {
+        let black = RGB::<u8>::new(0, 0, 0);
+        let white = RGB::<u8>::new(255, 255, 255);
+        assert!((apca_contrast(black, white) - 106.04).abs() < 0.01);
+        assert!((apca_contrast(white, black) + 107.88).abs() < 0.01);
+        assert!(apca_contrast(white, white).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn font_size() {
+        assert_eq!(apca_font_size(106.04, 400), Some(14.5));
+        assert_eq!(apca_font_size(-68.54, 700), Some(15.0));
+        assert_eq!(apca_font_size(62.0, 450), Some(24.0));
+        assert_eq!(apca_font_size(17.0, 900), None);
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{Channel, CompositeOperator, RGBA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The blend modes defined by W3C Compositing and Blending Level 1
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum BlendMode {
+    /// The source color replaces the backdrop
+    #[default]
+    Normal,
+    /// The source and backdrop are multiplied, which always darkens
+    Multiply,
+    /// The complements of the source and backdrop are multiplied, which
+    /// always lightens
+    Screen,
+    /// Multiplies or screens the colors depending on the backdrop
+    Overlay,
+    /// Selects the darker of each channel
+    Darken,
+    /// Selects the lighter of each channel
+    Lighten,
+    /// Brightens the backdrop to reflect the source
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source
+    ColorBurn,
+    /// Multiplies or screens the colors depending on the source
+    HardLight,
+    /// Darkens or lightens the colors depending on the source
+    SoftLight,
+    /// Subtracts the darker of each channel from the lighter
+    Difference,
+    /// Similar to `Difference`, but with lower contrast
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop
+    Luminosity,
+}
+
+impl BlendMode {
+    /// Blends a single channel for the separable blend modes
+    fn separable(self, backdrop: f32, source: f32) -> f32 {
+        match self {
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+            Self::Overlay => Self::HardLight.separable(source, backdrop),
+            Self::Darken => backdrop.min(source),
+            Self::Lighten => backdrop.max(source),
+            Self::ColorDodge => {
+                if backdrop <= 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if source <= 0.5 {
+                    Self::Multiply.separable(backdrop, 2.0 * source)
+                } else {
+                    Self::Screen.separable(backdrop, 2.0 * source - 1.0)
+                }
+            }
+            Self::SoftLight => {
+                if source <= 0.5 {
+                    backdrop - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        backdrop.sqrt()
+                    };
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            Self::Difference => (backdrop - source).abs(),
+            Self::Exclusion => backdrop + source - 2.0 * backdrop * source,
+            _ => source,
+        }
+    }
+
+    /// Calculates the blended color of the source and backdrop, before
+    /// compositing
+    fn apply(self, backdrop: [f32; 3], source: [f32; 3]) -> [f32; 3] {
+        match self {
+            Self::Hue => set_lum(set_sat(source, sat(backdrop)), lum(backdrop)),
+            Self::Saturation => set_lum(set_sat(backdrop, sat(source)), lum(backdrop)),
+            Self::Color => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+            _ => [
+                self.separable(backdrop[0], source[0]),
+                self.separable(backdrop[1], source[1]),
+                self.separable(backdrop[2], source[2]),
+            ],
+        }
+    }
+}
+
+fn lum(c: [f32; 3]) -> f32 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+fn clip_color(c: [f32; 3]) -> [f32; 3] {
+    let l = lum(c);
+    let min = c[0].min(c[1]).min(c[2]);
+    let max = c[0].max(c[1]).max(c[2]);
+    let mut c = c;
+    if min < 0.0 {
+        c = c.map(|x| l + (x - l) * l / (l - min));
+    }
+    if max > 1.0 {
+        c = c.map(|x| l + (x - l) * (1.0 - l) / (max - l));
+    }
+    c
+}
+
+fn set_lum(c: [f32; 3], l: f32) -> [f32; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|x| x + d))
+}
+
+fn sat(c: [f32; 3]) -> f32 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+fn set_sat(c: [f32; 3], s: f32) -> [f32; 3] {
+    let mut order = [0, 1, 2];
+    order.sort_by(|a, b| c[*a].total_cmp(&c[*b]));
+    let [min, mid, max] = order;
+    let mut out = [0.0; 3];
+    if c[max] > c[min] {
+        out[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        out[max] = s;
+    }
+    out
+}
+
+impl<T> RGBA<T>
+where
+    T: Channel,
+{
+    /// Blends this color as the source with a backdrop, then composites the
+    /// result over the backdrop. Where the backdrop is transparent the source
+    /// color is used unchanged, as described by the W3C compositing model.
+    /// ```
+    /// use rgba_simple::{BlendMode, RGBA};
+    ///
+    /// let gray = RGBA::<u8>::new(128, 128, 128, 255);
+    /// let orange = RGBA::<u8>::new(255, 128, 0, 255);
+    /// let blended = gray.blend(&orange, BlendMode::Multiply);
+    /// assert_eq!(blended, RGBA::new(128, 64, 0, 255));
+    /// ```
+    #[must_use]
+    pub fn blend(&self, backdrop: &Self, mode: BlendMode) -> Self {
+        let [sr, sg, sb, sa] = self.percentages();
+        let [br, bg, bb, ba] = backdrop.percentages();
+        let blended = mode.apply([br, bg, bb], [sr, sg, sb]);
+        let mix = |s: f32, b: f32| ((1.0 - ba) * s + ba * b).clamp(0.0, 1.0);
+        let source = [
+            mix(sr, blended[0]),
+            mix(sg, blended[1]),
+            mix(sb, blended[2]),
+            sa,
+        ];
+        Self::from_percentages(CompositeOperator::Over.apply(source, backdrop.percentages()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn separable() {
+        let backdrop = RGBA::<f32>::new(0.25, 0.5, 1.0, 1.0);
+        let source = RGBA::<f32>::new(0.5, 0.5, 0.5, 1.0);
+        let screen = source.blend(&backdrop, BlendMode::Screen);
+        assert_eq!(screen, RGBA::new(0.625, 0.75, 1.0, 1.0));
+        let difference = source.blend(&backdrop, BlendMode::Difference);
+        assert_eq!(difference, RGBA::new(0.25, 0.0, 0.5, 1.0));
+        let normal = source.blend(&backdrop, BlendMode::Normal);
+        assert_eq!(normal, source);
+    }
+
+    #[test]
+    fn non_separable() {
+        let backdrop = RGBA::<f32>::new(0.5, 0.5, 0.5, 1.0);
+        let red = RGBA::<f32>::new(1.0, 0.0, 0.0, 1.0);
+        let color = red.blend(&backdrop, BlendMode::Color);
+        let luminance = 0.3 * color.red + 0.59 * color.green + 0.11 * color.blue;
+        assert!((luminance - 0.5).abs() < 1e-6);
+        assert!(color.red > color.green && (color.green - color.blue).abs() < 1e-6);
+        let luminosity = red.blend(&backdrop, BlendMode::Luminosity);
+        assert!((luminosity.red - 0.3).abs() < 1e-6);
+        assert!((luminosity.red - luminosity.blue).abs() < 1e-6);
+    }
+
+    #[test]
+    fn alpha() {
+        let clear = RGBA::<f32>::new(0.0, 0.0, 0.0, 0.0);
+        let source = RGBA::<f32>::new(0.2, 0.4, 0.6, 1.0);
+        assert_eq!(source.blend(&clear, BlendMode::Multiply), source);
+        let backdrop = RGBA::<f32>::new(1.0, 1.0, 1.0, 1.0);
+        assert_eq!(clear.blend(&backdrop, BlendMode::Multiply), backdrop);
+    }
+}
@@ -1,4 +1,10 @@
-/// Required trait for a type to be able to represent a color channel
+use crate::ColorError;
+
+/// Required trait for a type to be able to represent a color channel.
+///
+/// Unsigned integer channels use the full range of the type, from zero to
+/// the type's maximum value. Signed integer channels range from zero to the
+/// type's maximum value, and floating point channels from 0.0 to 1.0.
 pub trait Channel: Copy + PartialOrd {
     const MAX: Self;
     const MIN: Self;
@@ -6,6 +12,20 @@ pub trait Channel: Copy + PartialOrd {
     fn channel_display(&self) -> String;
     fn from_percent(_: f32) -> Self;
     fn to_percent(&self) -> f32;
+    fn from_percent_f64(_: f64) -> Self;
+    fn to_percent_f64(&self) -> f64;
+
+    /// The value of an integer channel, used to convert exactly between
+    /// integer types. Returns `None` for floating point channels.
+    fn to_integer(&self) -> Option<u128> {
+        None
+    }
+
+    /// Creates an integer channel from it's value, returning `None` for
+    /// floating point channels or values which do not fit
+    fn from_integer(_: u128) -> Option<Self> {
+        None
+    }
 }
 
 impl Channel for u8 {
@@ -23,10 +43,26 @@ impl Channel for u8 {
     fn to_percent(&self) -> f32 {
         f32::from(*self) / 255.0
     }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * f64::from(u8::MAX)).round() as u8
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self) / f64::from(u8::MAX)
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        Some(u128::from(*self))
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
 }
 
 impl Channel for u16 {
-    const MAX: Self = 255;
+    const MAX: Self = u16::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -34,16 +70,32 @@ impl Channel for u16 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as u16
+        (percent * f32::from(u16::MAX)).round() as u16
     }
 
     fn to_percent(&self) -> f32 {
-        f32::from(*self) / 255.0
+        f32::from(*self) / f32::from(u16::MAX)
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * f64::from(u16::MAX)).round() as u16
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self) / f64::from(u16::MAX)
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        Some(u128::from(*self))
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
 impl Channel for u32 {
-    const MAX: Self = 255;
+    const MAX: Self = u32::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -51,16 +103,32 @@ impl Channel for u32 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as u32
+        (f64::from(percent) * f64::from(u32::MAX)).round() as u32
     }
 
     fn to_percent(&self) -> f32 {
-        *self as f32 / 255.0
+        (f64::from(*self) / f64::from(u32::MAX)) as f32
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * f64::from(u32::MAX)).round() as u32
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self) / f64::from(u32::MAX)
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        Some(u128::from(*self))
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
 impl Channel for u64 {
-    const MAX: Self = 255;
+    const MAX: Self = u64::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -68,16 +136,32 @@ impl Channel for u64 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as u64
+        (f64::from(percent) * u64::MAX as f64).round() as u64
     }
 
     fn to_percent(&self) -> f32 {
-        *self as f32 / 255.0
+        (*self as f64 / u64::MAX as f64) as f32
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * u64::MAX as f64).round() as u64
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        *self as f64 / u64::MAX as f64
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        Some(u128::from(*self))
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
 impl Channel for i16 {
-    const MAX: Self = 255;
+    const MAX: Self = i16::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -85,16 +169,32 @@ impl Channel for i16 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as i16
+        (percent * f32::from(i16::MAX)).round() as i16
     }
 
     fn to_percent(&self) -> f32 {
-        f32::from(*self) / 255.0
+        f32::from(*self) / f32::from(i16::MAX)
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * f64::from(i16::MAX)).round() as i16
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self) / f64::from(i16::MAX)
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
 impl Channel for i32 {
-    const MAX: Self = 255;
+    const MAX: Self = i32::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -102,16 +202,32 @@ impl Channel for i32 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as i32
+        (f64::from(percent) * f64::from(i32::MAX)).round() as i32
     }
 
     fn to_percent(&self) -> f32 {
-        *self as f32 / 255.0
+        (f64::from(*self) / f64::from(i32::MAX)) as f32
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * f64::from(i32::MAX)).round() as i32
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self) / f64::from(i32::MAX)
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
 impl Channel for i64 {
-    const MAX: Self = 255;
+    const MAX: Self = i64::MAX;
     const MIN: Self = 0;
 
     fn channel_display(&self) -> String {
@@ -119,11 +235,27 @@ impl Channel for i64 {
     }
 
     fn from_percent(percent: f32) -> Self {
-        (percent * 255.0).round() as i64
+        (f64::from(percent) * i64::MAX as f64).round() as i64
     }
 
     fn to_percent(&self) -> f32 {
-        *self as f32 / 255.0
+        (*self as f64 / i64::MAX as f64) as f32
+    }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        (percent * i64::MAX as f64).round() as i64
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        *self as f64 / i64::MAX as f64
+    }
+
+    fn to_integer(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn from_integer(value: u128) -> Option<Self> {
+        Self::try_from(value).ok()
     }
 }
 
@@ -142,6 +274,14 @@ impl Channel for f32 {
     fn to_percent(&self) -> f32 {
         *self
     }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        percent as f32
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        f64::from(*self)
+    }
 }
 
 impl Channel for f64 {
@@ -159,4 +299,56 @@ impl Channel for f64 {
     fn to_percent(&self) -> f32 {
         *self as f32
     }
+
+    fn from_percent_f64(percent: f64) -> Self {
+        percent
+    }
+
+    fn to_percent_f64(&self) -> f64 {
+        *self
+    }
+}
+
+/// Limits a channel value to the range `Channel::MIN..=Channel::MAX`
+pub(crate) fn clamp<T: Channel>(channel: T) -> T {
+    if channel < T::MIN {
+        T::MIN
+    } else if channel > T::MAX {
+        T::MAX
+    } else {
+        channel
+    }
+}
+
+/// Checks that every channel lies within `Channel::MIN..=Channel::MAX`
+pub(crate) fn validate<T: Channel>(channels: &[T]) -> Result<(), ColorError> {
+    for c in channels {
+        if *c < T::MIN {
+            return Err(ColorError::OutsideBoundsNegative);
+        } else if *c > T::MAX {
+            return Err(ColorError::OutsideBoundsHigh);
+        }
+    }
+    Ok(())
+}
+
+/// Converts a channel to another channel type. Conversions between integer
+/// types are calculated exactly, rounding to the nearest value of the new
+/// type, and all others go through an `f64` percentage.
+pub(crate) fn convert<T: Channel, U: Channel>(channel: T) -> U {
+    if let (Some(value), Some(max)) = (channel.to_integer(), T::MAX.to_integer()) {
+        return from_fraction(value, max);
+    }
+    U::from_percent_f64(channel.to_percent_f64())
+}
+
+/// Creates a channel from the fraction `value / max`, which is scaled exactly
+/// to the range of an integer channel, rounding to the nearest value
+pub(crate) fn from_fraction<T: Channel>(value: u128, max: u128) -> T {
+    if let Some(to_max) = T::MAX.to_integer() {
+        if let Some(scaled) = T::from_integer((value * to_max + max / 2) / max) {
+            return scaled;
+        }
+    }
+    T::from_percent_f64(value as f64 / max as f64)
 }
@@ -0,0 +1,156 @@
+use crate::{
+    matrix::{self, Matrix},
+    WhitePoint, Xyz,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Chromatic adaptation transforms, which convert XYZ to an approximation of
+/// the cone responses of the eye and scale them by the ratio of the source
+/// and destination white points
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Cat {
+    /// The transform used by ICC profiles and CSS
+    #[default]
+    Bradford,
+    /// The classic transform using the Hunt-Pointer-Estevez cone responses
+    VonKries,
+    /// The transform of the CIECAM02 color appearance model
+    Cat02,
+    /// The transform of the CAM16 color appearance model
+    Cat16,
+    /// Scales XYZ directly, the least accurate transform
+    XyzScaling,
+}
+
+const BRADFORD: Matrix = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const VON_KRIES: Matrix = [
+    [0.400_24, 0.7076, -0.080_81],
+    [-0.2263, 1.165_32, 0.0457],
+    [0.0, 0.0, 0.918_22],
+];
+
+const CAT02: Matrix = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+const CAT16: Matrix = [
+    [0.401_288, 0.650_173, -0.051_461],
+    [-0.250_268, 1.204_414, 0.045_854],
+    [-0.002_079, 0.048_952, 0.953_127],
+];
+
+const XYZ_SCALING: Matrix = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+impl Cat {
+    /// The matrix converting XYZ to cone responses
+    fn cone_response(self) -> Matrix {
+        match self {
+            Self::Bradford => BRADFORD,
+            Self::VonKries => VON_KRIES,
+            Self::Cat02 => CAT02,
+            Self::Cat16 => CAT16,
+            Self::XyzScaling => XYZ_SCALING,
+        }
+    }
+}
+
+/// Calculates the matrix which adapts XYZ values from one white point to
+/// another using the given transform
+#[must_use]
+pub fn adaptation_matrix(from: WhitePoint, to: WhitePoint, cat: Cat) -> [[f32; 3]; 3] {
+    let cone = cat.cone_response();
+    let source = matrix::transform(&cone, from.xyz().into());
+    let destination = matrix::transform(&cone, to.xyz().into());
+    let scale = matrix::diagonal([
+        destination[0] / source[0],
+        destination[1] / source[1],
+        destination[2] / source[2],
+    ]);
+    matrix::multiply(&matrix::invert(&cone), &matrix::multiply(&scale, &cone))
+}
+
+/// Adapts a color seen under one white point to how it would appear under
+/// another using the given transform
+/// ```
+/// use rgba_simple::{chromatic_adaptation::{self, Cat}, WhitePoint};
+///
+/// let white = chromatic_adaptation::adapt(
+///     WhitePoint::D65.xyz(),
+///     WhitePoint::D65,
+///     WhitePoint::D50,
+///     Cat::Cat16,
+/// );
+/// assert!((white.x - WhitePoint::D50.xyz().x).abs() < 1e-4);
+/// ```
+#[must_use]
+pub fn adapt(xyz: Xyz, from: WhitePoint, to: WhitePoint, cat: Cat) -> Xyz {
+    if from == to {
+        return xyz;
+    }
+    matrix::transform(&adaptation_matrix(from, to, cat), xyz.into()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATS: [Cat; 5] = [
+        Cat::Bradford,
+        Cat::VonKries,
+        Cat::Cat02,
+        Cat::Cat16,
+        Cat::XyzScaling,
+    ];
+
+    #[test]
+    fn bradford_d65_to_d50() {
+        let expected = [
+            [1.047_811, 0.022_886_6, -0.050_127],
+            [0.029_542_4, 0.990_484_4, -0.017_049_1],
+            [-0.009_234_5, 0.015_043_6, 0.752_131_6],
+        ];
+        let m = adaptation_matrix(WhitePoint::D65, WhitePoint::D50, Cat::Bradford);
+        for (row, expected) in m.iter().zip(expected) {
+            for (cell, expected) in row.iter().zip(expected) {
+                assert!((cell - expected).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn white_points() {
+        let points = [
+            WhitePoint::A,
+            WhitePoint::C,
+            WhitePoint::D50,
+            WhitePoint::D55,
+            WhitePoint::D65,
+            WhitePoint::D75,
+            WhitePoint::E,
+            WhitePoint::F2,
+            WhitePoint::F7,
+            WhitePoint::F11,
+        ];
+        for cat in CATS {
+            for from in points {
+                for to in points {
+                    let white = adapt(from.xyz(), from, to, cat);
+                    let expected = to.xyz();
+                    assert!((white.x - expected.x).abs() < 1e-4);
+                    assert!((white.y - expected.y).abs() < 1e-4);
+                    assert!((white.z - expected.z).abs() < 1e-4);
+                }
+            }
+        }
+    }
+}
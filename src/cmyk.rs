@@ -0,0 +1,260 @@
+use {
+    crate::{channel, Channel, ColorError, RGB, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color as the amounts of cyan, magenta, yellow and black ink
+/// used to print it.
+///
+/// The conversions do not use an ICC profile, instead treating each ink as
+/// an exact filter of it's complementary primary, so that
+/// `red = (1 - cyan) * (1 - black)`. This matches the naive conversion of
+/// CSS Color Module Level 5.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Cmyk<T>
+where
+    T: Channel,
+{
+    pub cyan: T,
+    pub magenta: T,
+    pub yellow: T,
+    pub black: T,
+}
+
+/// Controls how much of a color is printed with black ink when converting
+/// from `RGB` to `Cmyk`
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct CmykOptions {
+    /// Gray component replacement, the fraction from 0.0 to 1.0 of the gray
+    /// component shared by cyan, magenta and yellow which is printed with
+    /// black ink instead
+    pub gcr: f32,
+    /// Under color removal, the fraction from 0.0 to 1.0 of the color covered
+    /// by the black ink which is removed from cyan, magenta and yellow. Values
+    /// below 1.0 leave extra ink under the black, giving a richer black which
+    /// no longer converts back to exactly the same `RGB` value.
+    pub ucr: f32,
+}
+
+impl CmykOptions {
+    /// Replaces the whole gray component with black and removes the color
+    /// beneath it, giving the conversion of CSS Color Module Level 5
+    pub const NAIVE: Self = Self { gcr: 1.0, ucr: 1.0 };
+}
+
+impl Default for CmykOptions {
+    fn default() -> Self {
+        Self::NAIVE
+    }
+}
+
+impl<T> Cmyk<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(cyan: T, magenta: T, yellow: T, black: T) -> Result<Self, ColorError> {
+        channel::validate(&[cyan, magenta, yellow, black])?;
+        Ok(Self {
+            cyan,
+            magenta,
+            yellow,
+            black,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(cyan: T, magenta: T, yellow: T, black: T) -> Self {
+        Self {
+            cyan: channel::clamp(cyan),
+            magenta: channel::clamp(magenta),
+            yellow: channel::clamp(yellow),
+            black: channel::clamp(black),
+        }
+    }
+
+    /// Converts from `RGB` using the given amount of gray component
+    /// replacement and under color removal. Options outside of 0.0 to 1.0 are
+    /// clamped, and NaN or infinite options fall back to those of
+    /// `CmykOptions::NAIVE`.
+    /// ```
+    /// use rgba_simple::{Cmyk, CmykOptions, RGB};
+    ///
+    /// let gray = RGB::<u8>::new(51, 51, 51);
+    /// let naive = Cmyk::<u8>::from_rgb(gray, CmykOptions::NAIVE);
+    /// assert_eq!(naive, Cmyk::new(0, 0, 0, 204));
+    /// let half = Cmyk::<u8>::from_rgb(gray, CmykOptions { gcr: 0.5, ucr: 1.0 });
+    /// assert_eq!(half, Cmyk::new(170, 170, 170, 102));
+    /// assert_eq!(RGB::from(half), gray);
+    /// ```
+    pub fn from_rgb<U>(color: RGB<U>, options: CmykOptions) -> Self
+    where
+        U: Channel,
+    {
+        let [cyan, magenta, yellow] =
+            [color.red, color.green, color.blue].map(|c| 1.0 - c.to_percent());
+        let fraction = |value: f32, naive: f32| {
+            if value.is_finite() {
+                value.clamp(0.0, 1.0)
+            } else {
+                naive
+            }
+        };
+        let black = fraction(options.gcr, CmykOptions::NAIVE.gcr) * cyan.min(magenta).min(yellow);
+        let removed = fraction(options.ucr, CmykOptions::NAIVE.ucr) * black;
+        let remove = |c: f32| {
+            if removed < 1.0 {
+                ((c - removed) / (1.0 - removed)).clamp(0.0, 1.0)
+            } else {
+                0.0
+            }
+        };
+        Self {
+            cyan: T::from_percent(remove(cyan)),
+            magenta: T::from_percent(remove(magenta)),
+            yellow: T::from_percent(remove(yellow)),
+            black: T::from_percent(black),
+        }
+    }
+}
+
+impl<T> fmt::Display for Cmyk<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CMYK({}, {}, {}, {})",
+            self.cyan.channel_display(),
+            self.magenta.channel_display(),
+            self.yellow.channel_display(),
+            self.black.channel_display(),
+        )
+    }
+}
+
+impl<T, U> From<RGB<T>> for Cmyk<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    /// Converts using `CmykOptions::NAIVE`
+    fn from(color: RGB<T>) -> Self {
+        Self::from_rgb(color, CmykOptions::NAIVE)
+    }
+}
+
+impl<T, U> From<RGBA<T>> for Cmyk<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    /// Converts the color channels using `CmykOptions::NAIVE`, discarding the
+    /// alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Self::from_rgb(RGB::from(color), CmykOptions::NAIVE)
+    }
+}
+
+impl<T, U> From<Cmyk<T>> for RGB<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Cmyk<T>) -> Self {
+        let white = 1.0 - color.black.to_percent();
+        Self {
+            red: U::from_percent((1.0 - color.cyan.to_percent()) * white),
+            green: U::from_percent((1.0 - color.magenta.to_percent()) * white),
+            blue: U::from_percent((1.0 - color.yellow.to_percent()) * white),
+        }
+    }
+}
+
+impl<T, U> From<Cmyk<T>> for RGBA<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    /// Converts to a fully opaque color
+    fn from(color: Cmyk<T>) -> Self {
+        RGB::<U>::from(color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    #[test]
+    fn naive() {
+        let red: Cmyk<u8> = RGB::<u8>::from(PrimaryColor::Red).into();
+        assert_eq!(red, Cmyk::new(0, 255, 255, 0));
+        let black: Cmyk<u8> = RGBA::<u8>::new(0, 0, 0, 128).into();
+        assert_eq!(black, Cmyk::new(0, 0, 0, 255));
+        let olive = RGB::<u8>::new(128, 128, 0);
+        let cmyk: Cmyk<f32> = olive.into();
+        assert!(cmyk.cyan.abs() < 1e-6 && (cmyk.yellow - 1.0).abs() < 1e-6);
+        assert!((cmyk.black - 127.0 / 255.0).abs() < 1e-6);
+        assert_eq!(RGB::<u8>::from(cmyk), olive);
+    }
+
+    #[test]
+    fn options() {
+        let color = RGB::<u8>::new(200, 100, 50);
+        for gcr in [0.0, 0.25, 0.5, 1.0] {
+            let cmyk = Cmyk::<f32>::from_rgb(color, CmykOptions { gcr, ucr: 1.0 });
+            assert_eq!(RGB::<u8>::from(cmyk), color);
+        }
+        let none = Cmyk::<f32>::from_rgb(color, CmykOptions { gcr: 0.0, ucr: 1.0 });
+        assert!(none.black.abs() < 1e-6);
+        let rich = Cmyk::<u8>::from_rgb(
+            RGB::<u8>::from(PrimaryColor::Black),
+            CmykOptions { gcr: 1.0, ucr: 0.6 },
+        );
+        assert_eq!(rich, Cmyk::new(255, 255, 255, 255));
+        let dark = Cmyk::<f32>::from_rgb(
+            RGB::<f32>::new(0.2, 0.2, 0.2),
+            CmykOptions { gcr: 1.0, ucr: 0.5 },
+        );
+        assert!((dark.black - 0.8).abs() < 1e-6);
+        assert!((dark.cyan - 2.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn non_finite_options() {
+        let color = RGB::<u8>::new(200, 100, 50);
+        let naive = Cmyk::<u8>::from_rgb(color, CmykOptions::NAIVE);
+        for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let gcr = Cmyk::<u8>::from_rgb(
+                color,
+                CmykOptions {
+                    gcr: value,
+                    ucr: 1.0,
+                },
+            );
+            assert_eq!(gcr, naive);
+            let ucr = Cmyk::<u8>::from_rgb(
+                color,
+                CmykOptions {
+                    gcr: 1.0,
+                    ucr: value,
+                },
+            );
+            assert_eq!(ucr, naive);
+        }
+    }
+}
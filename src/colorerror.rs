@@ -11,21 +11,42 @@ pub enum ColorError {
     TruncatedHexString,
     HexStringOverflow,
     InvalidHexCharacter,
+    /// A bare word which is not a known color name
+    UnknownColorName(String),
+    /// A css function which is not a known color function
+    UnknownCssFunction(String),
+    /// A token within a css color function which could not be parsed
+    InvalidCssToken(String),
+    /// A css color function which has too few arguments
+    MissingCssToken(String),
+    /// A css color function whose comma separated arguments include an empty
+    /// argument
+    InvalidCssArguments(String),
+    /// An X11 color specification which could not be parsed
+    InvalidX11Spec(String),
+    /// No color has the requested contrast against the background
+    ContrastUnreachable,
+    /// A premultiplied color channel which is greater than the alpha channel
+    ExceedsAlpha,
 }
 
 impl Display for ColorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::OutsideBoundsNegative => "Value out of bounds: negative",
-                Self::OutsideBoundsHigh => "Value out of bounds: too high",
-                Self::TruncatedHexString => "Hex string is truncated",
-                Self::HexStringOverflow => "Extra characters in hex string",
-                Self::InvalidHexCharacter => "Invalid character in hex string",
-            }
-        )
+        match self {
+            Self::OutsideBoundsNegative => write!(f, "Value out of bounds: negative"),
+            Self::OutsideBoundsHigh => write!(f, "Value out of bounds: too high"),
+            Self::TruncatedHexString => write!(f, "Hex string is truncated"),
+            Self::HexStringOverflow => write!(f, "Extra characters in hex string"),
+            Self::InvalidHexCharacter => write!(f, "Invalid character in hex string"),
+            Self::UnknownColorName(name) => write!(f, "Unknown color name: {name}"),
+            Self::UnknownCssFunction(func) => write!(f, "Unknown css color function: {func}"),
+            Self::InvalidCssToken(token) => write!(f, "Invalid token in css color: {token}"),
+            Self::MissingCssToken(func) => write!(f, "Missing arguments to css function: {func}"),
+            Self::InvalidCssArguments(css) => write!(f, "Malformed css argument list: {css}"),
+            Self::InvalidX11Spec(spec) => write!(f, "Invalid X11 color specification: {spec}"),
+            Self::ContrastUnreachable => write!(f, "Requested contrast is unreachable"),
+            Self::ExceedsAlpha => write!(f, "Premultiplied color exceeds alpha"),
+        }
     }
 }
 
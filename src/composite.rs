@@ -0,0 +1,153 @@
+use crate::{linear, Channel, RGB, RGBA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The Porter-Duff operators for compositing a source color with a backdrop
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum CompositeOperator {
+    /// The source is placed over the backdrop
+    #[default]
+    Over,
+    /// The source is shown only where the backdrop is opaque
+    In,
+    /// The source is shown only where the backdrop is transparent
+    Out,
+    /// The source is placed over the backdrop, only where the backdrop is opaque
+    Atop,
+    /// The source and backdrop are each shown only where the other is transparent
+    Xor,
+    /// The source and backdrop are added together
+    Plus,
+}
+
+impl CompositeOperator {
+    /// The fractions of the source and backdrop which contribute to the result
+    fn factors(self, source_alpha: f32, backdrop_alpha: f32) -> (f32, f32) {
+        match self {
+            Self::Over => (1.0, 1.0 - source_alpha),
+            Self::In => (backdrop_alpha, 0.0),
+            Self::Out => (1.0 - backdrop_alpha, 0.0),
+            Self::Atop => (backdrop_alpha, 1.0 - source_alpha),
+            Self::Xor => (1.0 - backdrop_alpha, 1.0 - source_alpha),
+            Self::Plus => (1.0, 1.0),
+        }
+    }
+
+    /// Composites straight alpha percentages, returning straight alpha
+    pub(crate) fn apply(self, source: [f32; 4], backdrop: [f32; 4]) -> [f32; 4] {
+        let (fa, fb) = self.factors(source[3], backdrop[3]);
+        let alpha = (fa * source[3] + fb * backdrop[3]).min(1.0);
+        if alpha <= 0.0 {
+            return [0.0; 4];
+        }
+        let mix =
+            |s: f32, b: f32| ((fa * s * source[3] + fb * b * backdrop[3]) / alpha).clamp(0.0, 1.0);
+        [
+            mix(source[0], backdrop[0]),
+            mix(source[1], backdrop[1]),
+            mix(source[2], backdrop[2]),
+            alpha,
+        ]
+    }
+}
+
+impl<T> RGBA<T>
+where
+    T: Channel,
+{
+    /// Composites this color as the source over a backdrop using one of the
+    /// Porter-Duff operators. The calculation is performed directly on the
+    /// gamma encoded values, as most drawing libraries do.
+    /// ```
+    /// use rgba_simple::{CompositeOperator, RGBA};
+    ///
+    /// let red = RGBA::<u8>::new(255, 0, 0, 128);
+    /// let blue = RGBA::<u8>::new(0, 0, 255, 255);
+    /// let purple = red.composite(&blue, CompositeOperator::Over);
+    /// assert_eq!(purple, RGBA::new(128, 0, 127, 255));
+    /// ```
+    #[must_use]
+    pub fn composite(&self, backdrop: &Self, operator: CompositeOperator) -> Self {
+        Self::from_percentages(operator.apply(self.percentages(), backdrop.percentages()))
+    }
+
+    /// Composites this color as the source over a backdrop using one of the
+    /// Porter-Duff operators, with the calculation performed in linear light
+    #[must_use]
+    pub fn composite_linear(&self, backdrop: &Self, operator: CompositeOperator) -> Self {
+        let decode = |[r, g, b, a]: [f32; 4]| {
+            [
+                linear::srgb_decode(r),
+                linear::srgb_decode(g),
+                linear::srgb_decode(b),
+                a,
+            ]
+        };
+        let [r, g, b, a] =
+            operator.apply(decode(self.percentages()), decode(backdrop.percentages()));
+        Self::from_percentages([
+            linear::srgb_encode(r),
+            linear::srgb_encode(g),
+            linear::srgb_encode(b),
+            a,
+        ])
+    }
+
+    /// Places this color over an opaque background, giving the opaque color
+    /// which would be seen
+    #[must_use]
+    pub fn flatten_onto(&self, background: RGB<T>) -> RGB<T> {
+        self.composite(&background.into(), CompositeOperator::Over)
+            .into()
+    }
+
+    /// Places this color over an opaque background, giving the opaque color
+    /// which would be seen, with the calculation performed in linear light
+    #[must_use]
+    pub fn flatten_onto_linear(&self, background: RGB<T>) -> RGB<T> {
+        self.composite_linear(&background.into(), CompositeOperator::Over)
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators() {
+        let source = RGBA::<f32>::new(1.0, 0.0, 0.0, 0.5);
+        let backdrop = RGBA::<f32>::new(0.0, 0.0, 1.0, 0.5);
+        let over = source.composite(&backdrop, CompositeOperator::Over);
+        assert!((over.alpha - 0.75).abs() < 1e-6);
+        assert!((over.red - 2.0 / 3.0).abs() < 1e-6);
+        assert!((over.blue - 1.0 / 3.0).abs() < 1e-6);
+        let inside = source.composite(&backdrop, CompositeOperator::In);
+        assert_eq!(inside, RGBA::new(1.0, 0.0, 0.0, 0.25));
+        let out = source.composite(&backdrop, CompositeOperator::Out);
+        assert_eq!(out, RGBA::new(1.0, 0.0, 0.0, 0.25));
+        let atop = source.composite(&backdrop, CompositeOperator::Atop);
+        assert_eq!(atop, RGBA::new(0.5, 0.0, 0.5, 0.5));
+        let xor = source.composite(&backdrop, CompositeOperator::Xor);
+        assert_eq!(xor, RGBA::new(0.5, 0.0, 0.5, 0.5));
+        let plus = source.composite(&backdrop, CompositeOperator::Plus);
+        assert_eq!(plus, RGBA::new(0.5, 0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn flatten() {
+        let clear = RGBA::<u8>::new(255, 0, 0, 0);
+        let white = RGB::<u8>::new(255, 255, 255);
+        assert_eq!(clear.flatten_onto(white), white);
+        let half = RGBA::<u8>::new(0, 0, 0, 128);
+        assert_eq!(half.flatten_onto(white), RGB::new(127, 127, 127));
+        assert_eq!(half.flatten_onto_linear(white), RGB::new(187, 187, 187));
+        let transparent = RGBA::<u8>::new(0, 0, 0, 0);
+        assert_eq!(
+            transparent.composite(&transparent, CompositeOperator::Over),
+            transparent
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{linear, Channel, ColorError, Oklch, RGB, RGBA};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The conformance levels of the WCAG 2.1 contrast requirements
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum WcagLevel {
+    /// Success criterion 1.4.3, the minimum contrast
+    #[default]
+    AA,
+    /// Success criterion 1.4.6, the enhanced contrast
+    AAA,
+}
+
+/// The text sizes distinguished by the WCAG 2.1 contrast requirements
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum TextSize {
+    /// Text smaller than `Large`
+    #[default]
+    Normal,
+    /// Text of at least 18 point, or 14 point when bold
+    Large,
+}
+
+impl WcagLevel {
+    /// The minimum contrast ratio required for text of the given size
+    #[must_use]
+    pub fn minimum_ratio(self, text_size: TextSize) -> f32 {
+        match (self, text_size) {
+            (Self::AA, TextSize::Large) => 3.0,
+            (Self::AA, TextSize::Normal) | (Self::AAA, TextSize::Large) => 4.5,
+            (Self::AAA, TextSize::Normal) => 7.0,
+        }
+    }
+}
+
+/// Calculates the WCAG 2.1 contrast ratio between two colors, which ranges
+/// from 1.0 for identical colors to 21.0 for black against white. The order
+/// of the colors does not matter.
+/// ```
+/// use rgba_simple::{contrast_ratio, PrimaryColor, RGB};
+///
+/// let black: RGB<u8> = PrimaryColor::Black.into();
+/// let white: RGB<u8> = PrimaryColor::White.into();
+/// assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-4);
+/// ```
+#[must_use]
+pub fn contrast_ratio<T>(a: RGB<T>, b: RGB<T>) -> f32
+where
+    T: Channel,
+{
+    let a = a.relative_luminance();
+    let b = b.relative_luminance();
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+impl<T> RGB<T>
+where
+    T: Channel,
+{
+    /// The relative luminance of this color as defined by WCAG 2.1, ranging
+    /// from 0.0 for black to 1.0 for white
+    #[must_use]
+    pub fn relative_luminance(&self) -> f32 {
+        let [red, green, blue] =
+            [self.red, self.green, self.blue].map(|c| linear::srgb_decode(c.to_percent()));
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+
+    /// Checks whether this color, used as text against the given background,
+    /// meets the WCAG 2.1 contrast requirement of the given level
+    #[must_use]
+    pub fn meets_wcag(&self, background: Self, level: WcagLevel, text_size: TextSize) -> bool {
+        contrast_ratio(*self, background) >= level.minimum_ratio(text_size)
+    }
+
+    /// Finds the color closest to this one which has at least the target
+    /// contrast ratio against the background, by changing only the Oklch
+    /// lightness so that the hue is kept. The color is made both darker and
+    /// lighter and the smaller change is chosen. If neither direction reaches
+    /// the target, the chroma is dropped as well, giving a gray.
+    /// # Errors
+    /// Returns `ColorError::ContrastUnreachable` if not even black or white
+    /// has the target contrast against the background
+    /// ```
+    /// use rgba_simple::{contrast_ratio, RGB};
+    ///
+    /// let white = RGB::<u8>::new(255, 255, 255);
+    /// let yellow = RGB::<u8>::new(255, 200, 0);
+    /// let readable = yellow.adjust_for_contrast(white, 4.5).unwrap();
+    /// assert!(contrast_ratio(readable, white) >= 4.5);
+    /// ```
+    pub fn adjust_for_contrast(
+        &self,
+        background: Self,
+        target_ratio: f32,
+    ) -> Result<Self, ColorError> {
+        let passes = |color: Self| contrast_ratio(color, background) >= target_ratio;
+        if passes(*self) {
+            return Ok(*self);
+        }
+        let start = Oklch::from(*self);
+        for chroma in [start.chroma, 0.0] {
+            let with_lightness = |lightness: f32| -> Self {
+                Oklch {
+                    lightness,
+                    chroma,
+                    hue: start.hue,
+                }
+                .into()
+            };
+            let closest = [0.0, 1.0]
+                .into_iter()
+                .filter(|end| passes(with_lightness(*end)))
+                .map(|end| {
+                    let (mut near, mut far) = (start.lightness, end);
+                    for _ in 0..24 {
+                        let mid = f32::midpoint(near, far);
+                        if passes(with_lightness(mid)) {
+                            far = mid;
+                        } else {
+                            near = mid;
+                        }
+                    }
+                    (far, with_lightness(far))
+                })
+                .min_by(|a, b| {
+                    (a.0 - start.lightness)
+                        .abs()
+                        .total_cmp(&(b.0 - start.lightness).abs())
+                });
+            if let Some((_, color)) = closest {
+                return Ok(color);
+            }
+        }
+        Err(ColorError::ContrastUnreachable)
+    }
+}
+
+impl<T> RGBA<T>
+where
+    T: Channel,
+{
+    /// Calculates the WCAG 2.1 contrast ratio between this color and an
+    /// opaque background, after placing this color over the background
+    #[must_use]
+    pub fn contrast_ratio(&self, background: RGB<T>) -> f32 {
+        contrast_ratio(self.flatten_onto(background), background)
+    }
+
+    /// Checks whether this color, used as text against the given background,
+    /// meets the WCAG 2.1 contrast requirement of the given level after
+    /// placing it over the background
+    #[must_use]
+    pub fn meets_wcag(&self, background: RGB<T>, level: WcagLevel, text_size: TextSize) -> bool {
+        self.contrast_ratio(background) >= level.minimum_ratio(text_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn luminance() {
+        let white = RGB::<u8>::new(255, 255, 255);
+        assert!((white.relative_luminance() - 1.0).abs() < 1e-4);
+        let gray = RGB::<u8>::new(119, 119, 119);
+        assert!((contrast_ratio(gray, white) - 4.48).abs() < 0.01);
+        assert!(!gray.meets_wcag(white, WcagLevel::AA, TextSize::Normal));
+        assert!(gray.meets_wcag(white, WcagLevel::AA, TextSize::Large));
+        let darker = RGB::<u8>::new(118, 118, 118);
+        assert!(darker.meets_wcag(white, WcagLevel::AA, TextSize::Normal));
+        assert!(!darker.meets_wcag(white, WcagLevel::AAA, TextSize::Normal));
+    }
+
+    #[test]
+    fn translucent() {
+        let white = RGB::<u8>::new(255, 255, 255);
+        let black = RGBA::<u8>::new(0, 0, 0, 255);
+        assert!(black.meets_wcag(white, WcagLevel::AAA, TextSize::Normal));
+        let faint = RGBA::<u8>::new(0, 0, 0, 51);
+        assert!(faint.contrast_ratio(white) < 2.0);
+        assert!(!faint.meets_wcag(white, WcagLevel::AA, TextSize::Large));
+    }
+
+    #[test]
+    fn adjust() {
+        let white = RGB::<u8>::new(255, 255, 255);
+        let black = RGB::<u8>::new(0, 0, 0);
+        let red = RGB::<u8>::new(255, 0, 0);
+        let darker = red.adjust_for_contrast(white, 4.5).unwrap();
+        assert!(contrast_ratio(darker, white) >= 4.5);
+        assert!(contrast_ratio(darker, white) < 4.6);
+        assert!((Oklch::from(darker).hue - Oklch::from(red).hue).abs() < 2.0);
+        let lighter = red.adjust_for_contrast(black, 10.0).unwrap();
+        assert!(contrast_ratio(lighter, black) >= 10.0);
+        assert_eq!(black.adjust_for_contrast(white, 7.0), Ok(black));
+        let gray = RGB::<u8>::new(118, 118, 118);
+        assert_eq!(
+            red.adjust_for_contrast(gray, 7.0),
+            Err(ColorError::ContrastUnreachable)
+        );
+    }
+}
@@ -0,0 +1,368 @@
+use {
+    crate::{
+        hsl, hwb, linear, space, AdobeRgb, Channel, ColorError, DisplayP3, Hex, Lab, NamedColor,
+        Oklab, ProPhotoRgb, Rec2020, RgbColorSpace, WhitePoint, Xyz, RGBA,
+    },
+    std::str::FromStr,
+};
+
+/// Parses a color in any of the syntaxes defined by CSS Color Module Level 4.
+///
+/// Accepted forms are the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`,
+/// `lab()`, `lch()`, `oklab()`, `oklch()` and `color()` functions in both
+/// their legacy comma separated and modern space separated syntax, 3, 4, 6 and
+/// 8 digit hex notation, the named colors and `transparent`. Leading
+/// whitespace is ignored, but as in CSS a function name must be followed
+/// directly by it's opening parenthesis. Colors which lie outside of the sRGB
+/// gamut are clipped.
+/// ```
+/// use rgba_simple::{parse_css, RGBA};
+///
+/// let red: RGBA<u8> = parse_css("rgb(255 0 0 / 50%)").unwrap();
+/// assert_eq!(red, RGBA::new(255, 0, 0, 128));
+/// ```
+/// # Errors
+/// Returns `ColorError::UnknownColorName` if a bare word is not a named color
+/// Returns `ColorError::UnknownCssFunction` if the function name is not recognized
+/// Returns `ColorError::InvalidCssToken` if any argument can not be parsed, or
+/// if the arguments are too large to be converted to sRGB
+/// Returns `ColorError::MissingCssToken` if a function has too few arguments
+/// Returns `ColorError::InvalidCssArguments` if a comma separated argument is empty
+pub fn parse_css<T>(css: &str) -> Result<RGBA<T>, ColorError>
+where
+    T: Channel,
+{
+    let css = css.trim_start().to_ascii_lowercase();
+    let [red, green, blue, alpha] = if css.starts_with('#') {
+        parse_hex(&css)?
+    } else if let Some((name, args)) = css.strip_suffix(')').and_then(|f| f.split_once('(')) {
+        parse_function(name, args)?
+    } else if css == "transparent" {
+        [0.0; 4]
+    } else {
+        match NamedColor::from_name(&css) {
+            Some(color) => RGBA::<f32>::from(color).percentages(),
+            None => return Err(ColorError::UnknownColorName(css)),
+        }
+    };
+    Ok(RGBA {
+        red: T::from_percent(red.clamp(0.0, 1.0)),
+        green: T::from_percent(green.clamp(0.0, 1.0)),
+        blue: T::from_percent(blue.clamp(0.0, 1.0)),
+        alpha: T::from_percent(alpha.clamp(0.0, 1.0)),
+    })
+}
+
+impl<T> FromStr for RGBA<T>
+where
+    T: Channel,
+{
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_css(s)
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<[f32; 4], ColorError> {
+    // Css only allows for one or two digits per channel
+    if hex.len() > 9 {
+        return Err(ColorError::HexStringOverflow);
+    }
+    let color = RGBA::<f32>::from_hex(hex)?;
+    Ok([color.red, color.green, color.blue, color.alpha])
+}
+
+/// Splits the arguments of a css function into it's components and an
+/// optional alpha value
+fn split_args<'a>(
+    name: &str,
+    args: &'a str,
+) -> Result<(Vec<&'a str>, Option<&'a str>), ColorError> {
+    let (mut components, alpha) = if args.contains(',') {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        if parts.iter().any(|p| p.is_empty()) {
+            return Err(ColorError::InvalidCssArguments(format!("{name}({args})")));
+        }
+        if let Some(token) = parts.iter().find(|p| p.contains(char::is_whitespace)) {
+            return Err(ColorError::InvalidCssToken((*token).to_string()));
+        }
+        let alpha = if parts.len() > 3 {
+            Some(parts.remove(3))
+        } else {
+            None
+        };
+        (parts, alpha)
+    } else {
+        match args.split_once('/') {
+            Some((components, alpha)) => {
+                let mut alpha = alpha.split_whitespace();
+                let Some(first) = alpha.next() else {
+                    return Err(ColorError::MissingCssToken(name.to_string()));
+                };
+                if let Some(extra) = alpha.next() {
+                    return Err(ColorError::InvalidCssToken(extra.to_string()));
+                }
+                (components.split_whitespace().collect(), Some(first))
+            }
+            None => (args.split_whitespace().collect(), None),
+        }
+    };
+    let expected = if name == "color" { 4 } else { 3 };
+    if components.len() < expected {
+        return Err(ColorError::MissingCssToken(name.to_string()));
+    } else if components.len() > expected {
+        return Err(ColorError::InvalidCssToken(
+            components.remove(expected).to_string(),
+        ));
+    }
+    Ok((components, alpha))
+}
+
+/// Parses a single numeric component, where a percentage is scaled so that
+/// `100%` equals `reference`. The keyword `none` is treated as zero.
+fn number(token: &str, reference: f32) -> Result<f32, ColorError> {
+    let value = if token == "none" {
+        Some(0.0)
+    } else if let Some(percent) = token.strip_suffix('%') {
+        percent.parse::<f32>().ok().map(|p| p / 100.0 * reference)
+    } else {
+        token.parse::<f32>().ok()
+    };
+    match value {
+        Some(v) if v.is_finite() => Ok(v),
+        _ => Err(ColorError::InvalidCssToken(token.to_string())),
+    }
+}
+
+/// Parses a hue component, returning it as a fraction of a full turn
+fn hue(token: &str) -> Result<f32, ColorError> {
+    let (value, degrees) = if let Some(v) = token.strip_suffix("deg") {
+        (v, 360.0)
+    } else if let Some(v) = token.strip_suffix("grad") {
+        (v, 400.0)
+    } else if let Some(v) = token.strip_suffix("rad") {
+        (v, std::f32::consts::TAU)
+    } else if let Some(v) = token.strip_suffix("turn") {
+        (v, 1.0)
+    } else if token == "none" {
+        return Ok(0.0);
+    } else {
+        (token, 360.0)
+    };
+    match value.parse::<f32>() {
+        Ok(v) if v.is_finite() => Ok((v / degrees).rem_euclid(1.0)),
+        _ => Err(ColorError::InvalidCssToken(token.to_string())),
+    }
+}
+
+fn parse_function(name: &str, args: &str) -> Result<[f32; 4], ColorError> {
+    if !matches!(
+        name,
+        "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch" | "color"
+    ) {
+        return Err(ColorError::UnknownCssFunction(name.to_string()));
+    }
+    let (channels, alpha) = split_args(name, args)?;
+    let alpha = match alpha {
+        Some(a) => number(a, 1.0)?,
+        None => 1.0,
+    };
+    let [red, green, blue] = match name {
+        "rgb" | "rgba" => [
+            number(channels[0], 255.0)? / 255.0,
+            number(channels[1], 255.0)? / 255.0,
+            number(channels[2], 255.0)? / 255.0,
+        ],
+        "hsl" | "hsla" => {
+            let (r, g, b) = hsl::to_rgb(
+                hue(channels[0])?,
+                (number(channels[1], 100.0)? / 100.0).clamp(0.0, 1.0),
+                (number(channels[2], 100.0)? / 100.0).clamp(0.0, 1.0),
+            );
+            [r, g, b]
+        }
+        "hwb" => {
+            let (r, g, b) = hwb::to_rgb(
+                hue(channels[0])?,
+                (number(channels[1], 100.0)? / 100.0).clamp(0.0, 1.0),
+                (number(channels[2], 100.0)? / 100.0).clamp(0.0, 1.0),
+            );
+            [r, g, b]
+        }
+        "lab" => lab_to_srgb(
+            number(channels[0], 100.0)?.clamp(0.0, 100.0),
+            number(channels[1], 125.0)?,
+            number(channels[2], 125.0)?,
+        ),
+        "lch" => {
+            let (a, b) = polar_to_cartesian(number(channels[1], 150.0)?, hue(channels[2])?);
+            lab_to_srgb(number(channels[0], 100.0)?.clamp(0.0, 100.0), a, b)
+        }
+        "oklab" => oklab_to_srgb(
+            number(channels[0], 1.0)?.clamp(0.0, 1.0),
+            number(channels[1], 0.4)?,
+            number(channels[2], 0.4)?,
+        ),
+        "oklch" => {
+            let (a, b) = polar_to_cartesian(number(channels[1], 0.4)?, hue(channels[2])?);
+            oklab_to_srgb(number(channels[0], 1.0)?.clamp(0.0, 1.0), a, b)
+        }
+        _ => color_to_srgb(
+            channels[0],
+            [
+                number(channels[1], 1.0)?,
+                number(channels[2], 1.0)?,
+                number(channels[3], 1.0)?,
+            ],
+        )?,
+    };
+    if ![red, green, blue].iter().all(|c| c.is_finite()) {
+        return Err(ColorError::InvalidCssToken(format!("{name}({args})")));
+    }
+    Ok([red, green, blue, alpha])
+}
+
+/// Formats a number for use in css, rounded to at most three decimal places
+/// and without trailing zeros or negative zero
+pub(crate) fn format_number(value: f32) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    format!("{}", rounded + 0.0)
+}
+
+fn polar_to_cartesian(chroma: f32, hue: f32) -> (f32, f32) {
+    let chroma = chroma.max(0.0);
+    let (sin, cos) = (hue * std::f32::consts::TAU).sin_cos();
+    (chroma * cos, chroma * sin)
+}
+
+/// Converts to gamma encoded sRGB without clipping, so that a conversion
+/// which overflows can be detected
+fn xyz_to_srgb(xyz: [f32; 3]) -> [f32; 3] {
+    Xyz::from(xyz).to_linear_srgb().map(linear::srgb_encode)
+}
+
+fn d50_to_srgb(xyz: [f32; 3]) -> [f32; 3] {
+    xyz_to_srgb(
+        Xyz::from(xyz)
+            .adapt(WhitePoint::D50, WhitePoint::D65)
+            .into(),
+    )
+}
+
+fn lab_to_srgb(lightness: f32, a: f32, b: f32) -> [f32; 3] {
+    d50_to_srgb(Lab { lightness, a, b }.to_xyz(WhitePoint::D50).into())
+}
+
+fn oklab_to_srgb(lightness: f32, a: f32, b: f32) -> [f32; 3] {
+    Oklab { lightness, a, b }
+        .to_linear_srgb()
+        .map(linear::srgb_encode)
+}
+
+/// Converts the channels of a `color()` function in the named color space
+fn color_to_srgb(name: &str, c: [f32; 3]) -> Result<[f32; 3], ColorError> {
+    Ok(match name {
+        "srgb" => c,
+        "srgb-linear" => c.map(linear::srgb_encode),
+        DisplayP3::CSS_NAME => xyz_to_srgb(space::to_xyz::<DisplayP3>(c).into()),
+        AdobeRgb::CSS_NAME => xyz_to_srgb(space::to_xyz::<AdobeRgb>(c).into()),
+        ProPhotoRgb::CSS_NAME => xyz_to_srgb(space::to_xyz::<ProPhotoRgb>(c).into()),
+        Rec2020::CSS_NAME => xyz_to_srgb(space::to_xyz::<Rec2020>(c).into()),
+        "xyz" | "xyz-d65" => xyz_to_srgb(c),
+        "xyz-d50" => d50_to_srgb(c),
+        _ => return Err(ColorError::InvalidCssToken(name.to_string())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions() {
+        let red = RGBA::<u8>::new(255, 0, 0, 255);
+        for css in [
+            "rgb(255, 0, 0)",
+            "rgba(100%, 0%, 0%, 1)",
+            "rgb(255 0 0 / 100%)",
+            "hsl(0 100% 50%)",
+            "hsla(360deg, 100%, 50%, 1.0)",
+            "hwb(0turn 0% 0%)",
+            "lab(54.29 80.82 69.89)",
+            "lch(54.29 106.84 40.85)",
+            "oklab(0.628 0.2249 0.1258)",
+            "oklch(62.8% 0.2577 29.23deg)",
+            "color(srgb 1 0 0)",
+            "color(xyz-d65 0.4124 0.2126 0.0193)",
+            "color(display-p3 0.9175 0.2003 0.1386)",
+            "RED",
+            "#f00",
+            "#ff0000ff",
+        ] {
+            assert_eq!(parse_css::<u8>(css), Ok(red), "{css}");
+        }
+    }
+
+    #[test]
+    fn alpha() {
+        let color: RGBA<u8> = "rgb(255 0 0 / 50%)".parse().unwrap();
+        assert_eq!(color, RGBA::new(255, 0, 0, 128));
+        let color: RGBA<f32> = "#0000ff80".parse().unwrap();
+        assert!((color.alpha - 128.0 / 255.0).abs() < f32::EPSILON);
+        assert_eq!(parse_css::<u8>("transparent"), Ok(RGBA::new(0, 0, 0, 0)));
+        assert_eq!(
+            parse_css::<u8>("rebeccapurple"),
+            Ok(RGBA::new(0x66, 0x33, 0x99, 255))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse_css::<u8>("rgb(255 zero 0)"),
+            Err(ColorError::InvalidCssToken("zero".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("rgb(255 0)"),
+            Err(ColorError::MissingCssToken("rgb".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("rgb(255 0 0 0)"),
+            Err(ColorError::InvalidCssToken("0".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("rgb (1 2 3)"),
+            Err(ColorError::UnknownCssFunction("rgb ".to_string()))
+        );
+        assert_eq!(parse_css::<u8>("  rgb(1 2 3)"), Ok(RGBA::new(1, 2, 3, 255)));
+        assert_eq!(
+            parse_css::<u8>("rgb(255,0,0,)"),
+            Err(ColorError::InvalidCssArguments("rgb(255,0,0,)".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("cmyk(0 0 0 0)"),
+            Err(ColorError::UnknownCssFunction("cmyk".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("color(foo 1 0 0)"),
+            Err(ColorError::InvalidCssToken("foo".to_string()))
+        );
+        assert_eq!(
+            parse_css::<u8>("lab(100 1e38 1e38)"),
+            Err(ColorError::InvalidCssToken(
+                "lab(100 1e38 1e38)".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_css::<u8>("color(display-p3 1e38 0 0)"),
+            Err(ColorError::InvalidCssToken(
+                "color(display-p3 1e38 0 0)".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_css::<u8>("bluish"),
+            Err(ColorError::UnknownColorName("bluish".to_string()))
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Measures of the perceived difference between two colors, calculated in the
+//! CIELAB color space relative to D65. Any type which converts into `Lab`,
+//! including `RGB` and `RGBA`, can be compared. A difference of about 1.0 is
+//! the smallest which most people can see.
+//! ```
+//! use rgba_simple::{delta_e, RGB};
+//!
+//! let a = RGB::<f32>::new(0.5, 0.25, 0.75);
+//! let b = RGB::<f32>::new(0.501, 0.25, 0.749);
+//! assert!(delta_e::ciede2000(a, b) < 1.0);
+//! ```
+//! `cie94` and `cmc` are not symmetric, the first color being the reference
+//! which the second is compared against.
+use crate::Lab;
+
+/// The hue angle in degrees of the `a` and `b` coordinates
+fn hue(a: f32, b: f32) -> f32 {
+    if a == 0.0 && b == 0.0 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    }
+}
+
+/// The square of the difference in hue, derived from the differences in
+/// `a`, `b` and chroma
+fn hue_difference_squared(reference: &Lab, sample: &Lab, chroma_difference: f32) -> f32 {
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    (da * da + db * db - chroma_difference * chroma_difference).max(0.0)
+}
+
+/// The CIE 1976 color difference, the Euclidean distance between the colors
+/// in Lab
+#[must_use]
+pub fn cie76(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    let reference = reference.into();
+    let sample = sample.into();
+    (reference.lightness - sample.lightness)
+        .hypot(reference.a - sample.a)
+        .hypot(reference.b - sample.b)
+}
+
+/// The CIE 1994 color difference, using the weights for graphic arts
+#[must_use]
+pub fn cie94(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    let reference = reference.into();
+    let sample = sample.into();
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let dl = reference.lightness - sample.lightness;
+    let dc = c1 - c2;
+    let dh2 = hue_difference_squared(&reference, &sample, dc);
+    let sc = 1.0 + 0.045 * c1;
+    let sh = 1.0 + 0.015 * c1;
+    (dl * dl + (dc / sc).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+/// The CIEDE2000 color difference, which corrects the remaining perceptual
+/// non-uniformity of CIELAB, particularly in the blue hues
+#[must_use]
+pub fn ciede2000(reference: impl Into<Lab>, sample: impl Into<Lab>) -> f32 {
+    let reference = reference.into();
+    let sample = sample.into();
+    let c_mean = f32::midpoint(reference.a.hypot(reference.b), sample.a.hypot(sample.b));
+    let c7 = c_mean.powi(7);
+    let g = 0.5 * (1.0 - (c7 / (c7 + 25f32.powi(7))).sqrt());
+    let a1 = (1.0 + g) * reference.a;
+    let a2 = (1.0 + g) * sample.a;
+    let c1 = a1.hypot(reference.b);
+    let c2 = a2.hypot(sample.b);
+    let h1 = hue(a1, reference.b);
+    let h2 = hue(a2, sample.b);
+
+    let dl = sample.lightness - reference.lightness;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = f32::midpoint(reference.lightness, sample.lightness);
+    let c_mean = f32::midpoint(c1, c2);
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        f32::midpoint(h1, h2)
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let rotation = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let c7 = c_mean.powi(7);
+    let rc = 2.0 * (c7 / (c7 + 25f32.powi(7))).sqrt();
+    let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+    let rt = -(2.0 * rotation).to_radians().sin() * rc;
+    ((dl / sl).powi(2) + (dc / sc).powi(2) + (dh / sh).powi(2) + rt * (dc / sc) * (dh / sh)).sqrt()
+}
+
+/// The CMC l:c color difference, where `lightness` and `chroma` weight the
+/// importance of those differences. 2:1 is commonly used to judge whether
+/// colors are acceptably close and 1:1 whether they can be told apart.
+#[must_use]
+pub fn cmc(reference: impl Into<Lab>, sample: impl Into<Lab>, lightness: f32, chroma: f32) -> f32 {
+    let reference = reference.into();
+    let sample = sample.into();
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let h1 = hue(reference.a, reference.b);
+    let dl = reference.lightness - sample.lightness;
+    let dc = c1 - c2;
+    let dh2 = hue_difference_squared(&reference, &sample, dc);
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sl = if reference.lightness < 16.0 {
+        0.511
+    } else {
+        0.040_975 * reference.lightness / (1.0 + 0.017_65 * reference.lightness)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let sh = sc * (f * t + 1.0 - f);
+    ((dl / (lightness * sl)).powi(2) + (dc / (chroma * sc)).powi(2) + dh2 / (sh * sh)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RGB;
+
+    fn lab(lightness: f32, a: f32, b: f32) -> Lab {
+        Lab { lightness, a, b }
+    }
+
+    #[test]
+    fn sharma() {
+        // Test data from Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference
+        // Formula: Implementation Notes, Supplementary Test Data, and
+        // Mathematical Observations"
+        let pairs = [
+            (
+                lab(50.0, 2.6772, -79.7751),
+                lab(50.0, 0.0, -82.7485),
+                2.0425,
+            ),
+            (lab(50.0, -1.0, 2.0), lab(50.0, 0.0, 0.0), 2.3669),
+            (lab(50.0, 2.49, -0.001), lab(50.0, -2.49, 0.0011), 7.2195),
+            (lab(50.0, 2.5, 0.0), lab(73.0, 25.0, -18.0), 27.1492),
+            (
+                lab(60.2574, -34.0099, 36.2677),
+                lab(60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                lab(2.0776, 0.0795, -1.135),
+                lab(0.9033, -0.0636, -0.5514),
+                0.9082,
+            ),
+        ];
+        for (reference, sample, expected) in pairs {
+            assert!((ciede2000(reference, sample) - expected).abs() < 1e-3);
+            assert!((ciede2000(sample, reference) - expected).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn metrics() {
+        let reference = lab(50.0, 2.5, 0.0);
+        let sample = lab(73.0, 25.0, -18.0);
+        assert!((cie76(reference, sample) - 36.868).abs() < 1e-3);
+        assert!((cie94(reference, sample) - 34.6892).abs() < 1e-3);
+        assert!((cmc(reference, sample, 1.0, 1.0) - 42.1088).abs() < 1e-3);
+        let color = RGB::<u8>::new(100, 149, 237);
+        assert!(cie76(color, color).abs() < f32::EPSILON);
+        assert!(ciede2000(color, color).abs() < f32::EPSILON);
+        assert!(cmc(color, color, 2.0, 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    linear, space, Channel, Lab, Lch, Oklab, Oklch, RgbColorSpace, Srgb, TaggedRGBA, Xyz, RGB,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The largest difference in Oklab which is considered imperceptible when
+/// reducing chroma
+const JND: f32 = 0.02;
+/// The precision of the search for the largest chroma within the gamut
+const EPSILON: f32 = 0.0001;
+/// The tolerance for rounding errors when checking whether a channel is in
+/// the range `0.0..=1.0`
+const TOLERANCE: f32 = 0.000_01;
+
+/// How a color which lies outside of the sRGB gamut is brought inside it
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum GamutMapMode {
+    /// The algorithm of CSS Color Module Level 4, which reduces the Oklch
+    /// chroma until the color is within a just noticeable difference of the
+    /// gamut, keeping the lightness and hue
+    #[default]
+    Chroma,
+    /// Each channel is clamped independently, which can shift the hue of
+    /// vivid colors
+    Clip,
+}
+
+/// Conversion of colors in spaces larger than sRGB into the sRGB gamut
+/// ```
+/// use rgba_simple::{GamutMap, GamutMapMode, Oklch, RGB};
+///
+/// let vivid = Oklch { lightness: 0.7, chroma: 0.4, hue: 150.0 };
+/// assert!(!vivid.in_gamut());
+/// let mapped: RGB<u8> = vivid.gamut_map(GamutMapMode::Chroma);
+/// let clipped: RGB<u8> = vivid.gamut_map(GamutMapMode::Clip);
+/// assert_ne!(mapped, clipped);
+/// ```
+pub trait GamutMap {
+    /// Checks whether the color can be represented in sRGB without clipping
+    fn in_gamut(&self) -> bool;
+
+    /// Converts the color to sRGB, bringing it within the gamut using the
+    /// given mode
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel;
+}
+
+/// Converts to gamma encoded sRGB without clipping
+fn to_srgb(color: Oklab) -> [f32; 3] {
+    color.to_linear_srgb().map(linear::srgb_encode)
+}
+
+fn in_gamut(rgb: [f32; 3]) -> bool {
+    rgb.iter()
+        .all(|c| (-TOLERANCE..=1.0 + TOLERANCE).contains(c))
+}
+
+fn clip(rgb: [f32; 3]) -> [f32; 3] {
+    rgb.map(|c| c.clamp(0.0, 1.0))
+}
+
+/// The Euclidean distance in Oklab between an Oklch color and a color in
+/// gamma encoded sRGB
+fn delta_eok(color: Oklch, rgb: [f32; 3]) -> f32 {
+    let a = Oklab::from(color);
+    let b = Oklab::from_linear_srgb(rgb.map(linear::srgb_decode));
+    (a.lightness - b.lightness)
+        .hypot(a.a - b.a)
+        .hypot(a.b - b.b)
+}
+
+/// Maps a color to gamma encoded sRGB percentages
+fn map(color: Oklch, mode: GamutMapMode) -> [f32; 3] {
+    let origin = to_srgb(color.into());
+    if mode == GamutMapMode::Clip || in_gamut(origin) {
+        return clip(origin);
+    }
+    if color.lightness >= 1.0 {
+        return [1.0; 3];
+    } else if color.lightness <= 0.0 {
+        return [0.0; 3];
+    }
+    let mut clipped = clip(origin);
+    if delta_eok(color, clipped) < JND {
+        return clipped;
+    }
+    let mut current = color;
+    let mut min = 0.0;
+    let mut max = color.chroma;
+    let mut min_in_gamut = true;
+    while max - min > EPSILON {
+        current.chroma = f32::midpoint(min, max);
+        let rgb = to_srgb(current.into());
+        if min_in_gamut && in_gamut(rgb) {
+            min = current.chroma;
+            continue;
+        }
+        clipped = clip(rgb);
+        let e = delta_eok(current, clipped);
+        if e < JND {
+            if JND - e < EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = current.chroma;
+        } else {
+            max = current.chroma;
+        }
+    }
+    clipped
+}
+
+fn to_rgb<T>(rgb: [f32; 3]) -> RGB<T>
+where
+    T: Channel,
+{
+    RGB {
+        red: T::from_percent(rgb[0]),
+        green: T::from_percent(rgb[1]),
+        blue: T::from_percent(rgb[2]),
+    }
+}
+
+impl GamutMap for Oklch {
+    fn in_gamut(&self) -> bool {
+        in_gamut(to_srgb((*self).into()))
+    }
+
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        to_rgb(map(*self, mode))
+    }
+}
+
+impl GamutMap for Oklab {
+    fn in_gamut(&self) -> bool {
+        in_gamut(to_srgb(*self))
+    }
+
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        Oklch::from(*self).gamut_map(mode)
+    }
+}
+
+impl GamutMap for Xyz {
+    fn in_gamut(&self) -> bool {
+        Oklab::from_linear_srgb(self.to_linear_srgb()).in_gamut()
+    }
+
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        Oklab::from_linear_srgb(self.to_linear_srgb()).gamut_map(mode)
+    }
+}
+
+impl GamutMap for Lab {
+    fn in_gamut(&self) -> bool {
+        Xyz::from(*self).in_gamut()
+    }
+
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        Xyz::from(*self).gamut_map(mode)
+    }
+}
+
+impl GamutMap for Lch {
+    fn in_gamut(&self) -> bool {
+        Lab::from(*self).in_gamut()
+    }
+
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        Lab::from(*self).gamut_map(mode)
+    }
+}
+
+impl<U, S> GamutMap for TaggedRGBA<U, S>
+where
+    U: Channel,
+    S: RgbColorSpace,
+{
+    /// Checks whether the color can be represented in sRGB, ignoring the
+    /// alpha channel
+    fn in_gamut(&self) -> bool {
+        in_gamut(space::from_xyz::<Srgb>(self.to_xyz()))
+    }
+
+    /// Converts the color channels to sRGB, discarding the alpha channel
+    fn gamut_map<T>(&self, mode: GamutMapMode) -> RGB<T>
+    where
+        T: Channel,
+    {
+        self.to_xyz().gamut_map(mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DisplayP3, RGBA};
+
+    #[test]
+    fn keeps_hue() {
+        let vivid = Oklch {
+            lightness: 0.7,
+            chroma: 0.4,
+            hue: 150.0,
+        };
+        let mapped: RGB<f32> = vivid.gamut_map(GamutMapMode::Chroma);
+        let oklch = Oklch::from(mapped);
+        assert!((oklch.hue - 150.0).abs() < 4.0);
+        assert!((oklch.lightness - 0.7).abs() < 0.02);
+        let clipped: RGB<f32> = vivid.gamut_map(GamutMapMode::Clip);
+        assert!((Oklch::from(clipped).hue - 150.0).abs() > (oklch.hue - 150.0).abs());
+    }
+
+    #[test]
+    fn in_gamut() {
+        for color in [
+            RGB::<u8>::new(255, 0, 0),
+            RGB::new(255, 255, 255),
+            RGB::new(0, 0, 0),
+            RGB::new(12, 200, 99),
+        ] {
+            assert!(Oklch::from(color).in_gamut());
+            assert!(Lab::from(color).in_gamut());
+            assert_eq!(
+                Oklch::from(color).gamut_map::<u8>(GamutMapMode::Chroma),
+                color
+            );
+        }
+        let p3 = TaggedRGBA::<u8, DisplayP3>::new(RGBA::new(0, 255, 0, 255));
+        assert!(!p3.in_gamut());
+        let mapped: RGB<u8> = p3.gamut_map(GamutMapMode::Chroma);
+        assert!(mapped.green > 200);
+        let white = Oklch {
+            lightness: 1.0,
+            chroma: 0.2,
+            hue: 90.0,
+        };
+        assert_eq!(
+            white.gamut_map::<u8>(GamutMapMode::Chroma),
+            RGB::new(255, 255, 255)
+        );
+    }
+}
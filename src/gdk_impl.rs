@@ -60,41 +60,15 @@ impl Hex for gdk::RGBA {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!(
-            "#{}{}{}",
-            self.red().to_hex(),
-            self.green().to_hex(),
-            self.blue().to_hex(),
-        )
+        RGBA::new(self.red(), self.green(), self.blue(), self.alpha()).to_hex()
+    }
+
+    fn to_hex_short(&self) -> String {
+        RGBA::new(self.red(), self.green(), self.blue(), self.alpha()).to_hex_short()
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        crate::hex::validate_hex_string(hex)?;
-        let red = match f32::from_hex(&hex[1..3]) {
-            Ok(r) => r,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        let green = match f32::from_hex(&hex[3..5]) {
-            Ok(g) => g,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        let blue = match f32::from_hex(&hex[5..7]) {
-            Ok(b) => b,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        for channel in [red, green, blue] {
-            if channel < 0.0 {
-                return Err(ColorError::OutsideBoundsNegative);
-            } else if channel > 1.0 {
-                return Err(ColorError::OutsideBoundsHigh);
-            }
-        }
-        Ok(gdk::builders::RGBABuilder::new()
-            .red(red)
-            .green(green)
-            .blue(blue)
-            .alpha(1.0)
-            .build())
+        RGBA::<f32>::from_hex(hex).map(Into::into)
     }
 }
 
@@ -125,7 +99,16 @@ mod tests {
     #[test]
     fn u8_to_gdk() {
         let red: gdk::RGBA = RGBA::<u8>::try_new(255, 0, 0, 0).unwrap().into();
-        assert_eq!(1.0, red.red());
-        assert_eq!(0.0, red.blue());
+        assert!((red.red() - 1.0).abs() < f32::EPSILON);
+        assert!(red.blue().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        let color = gdk::RGBA::from_hex("#ff8000cc").unwrap();
+        assert_eq!(color.to_hex(), "#ff8000cc");
+        assert_eq!(RGBA::<u8>::from(color), RGBA::new(255, 128, 0, 204));
+        let opaque = gdk::RGBA::from_hex("#f80").unwrap();
+        assert_eq!(opaque.to_hex_short(), "#f80");
     }
 }
@@ -1,17 +1,92 @@
-use crate::ColorError;
-
-pub(crate) fn validate_hex_string(hex: &str) -> Result<(), ColorError> {
-    match &hex.len() {
-        x if *x < 7 => Err(ColorError::TruncatedHexString),
-        x if *x > 7 => Err(ColorError::HexStringOverflow),
-        _ => {
-            if &hex[0..1] == "#" {
-                Ok(())
-            } else {
-                Err(ColorError::InvalidHexCharacter)
+use crate::{Channel, ColorError};
+
+/// The number of hex digits which may be used to represent each channel
+const WIDTHS: [usize; 5] = [1, 2, 4, 8, 16];
+
+/// Splits a hex color string into equal width groups of digits, one for each
+/// channel. The string must begin with '#' and contain three channels, or
+/// either three or four channels if `alpha` is true.
+pub(crate) fn split_channels(hex: &str, alpha: bool) -> Result<Vec<&str>, ColorError> {
+    let Some(digits) = hex.strip_prefix('#') else {
+        return Err(ColorError::InvalidHexCharacter);
+    };
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ColorError::InvalidHexCharacter);
+    }
+    let counts: &[usize] = if alpha { &[3, 4] } else { &[3] };
+    let len = digits.len();
+    let mut valid = vec![];
+    for count in counts {
+        for width in WIDTHS {
+            if count * width == len {
+                return Ok((0..*count)
+                    .map(|i| &digits[i * width..(i + 1) * width])
+                    .collect());
             }
+            valid.push(count * width);
         }
     }
+    let below = valid.iter().filter(|n| **n < len).max();
+    let above = valid.iter().filter(|n| **n > len).min();
+    match (below, above) {
+        (Some(b), Some(a)) if len - b < a - len => Err(ColorError::HexStringOverflow),
+        (_, Some(_)) => Err(ColorError::TruncatedHexString),
+        _ => Err(ColorError::HexStringOverflow),
+    }
+}
+
+/// Joins the hex representations of each channel into a color string, using
+/// the fewest digits per channel which still represent the same values
+pub(crate) fn shortest(channels: &[String]) -> String {
+    let mut width = channels.first().map_or(0, String::len);
+    while width > 1
+        && width.is_multiple_of(2)
+        && channels.iter().all(|c| {
+            let c = c.as_bytes();
+            c.chunks(width / 2).all(|chunk| chunk == &c[..width / 2])
+        })
+    {
+        width /= 2;
+    }
+    channels.iter().fold(String::from("#"), |mut hex, c| {
+        hex.push_str(&c[..width]);
+        hex
+    })
+}
+
+/// Scales a value in the range `0..=from` to the range `0..=to`, rounding to
+/// the nearest integer
+pub(crate) fn rescale(value: u64, from: u64, to: u64) -> u64 {
+    if from == to {
+        return value;
+    }
+    let value = u128::from(value) * u128::from(to) + u128::from(from / 2);
+    (value / u128::from(from)) as u64
+}
+
+/// Parses a group of hex digits, scaling the value from the range which can be
+/// represented by that many digits to `0..=max`
+pub(crate) fn scaled(hex: &str, max: u64) -> Result<u64, ColorError> {
+    if hex.is_empty() || hex.len() > 16 {
+        return Err(ColorError::InvalidHexCharacter);
+    }
+    let Ok(value) = u64::from_str_radix(hex, 16) else {
+        return Err(ColorError::InvalidHexCharacter);
+    };
+    Ok(rescale(value, u64::MAX >> (64 - 4 * hex.len()), max))
+}
+
+/// Parses a group of hex digits as a fraction of the largest value which can
+/// be represented by that many digits
+pub(crate) fn fraction(hex: &str) -> Result<f64, ColorError> {
+    if hex.is_empty() || hex.len() > 16 {
+        return Err(ColorError::InvalidHexCharacter);
+    }
+    let Ok(value) = u64::from_str_radix(hex, 16) else {
+        return Err(ColorError::InvalidHexCharacter);
+    };
+    let range = u64::MAX >> (64 - 4 * hex.len());
+    Ok(value as f64 / range as f64)
 }
 
 /// Transformations to and from hexadecimal notation (base 16)
@@ -19,6 +94,11 @@ pub trait Hex {
     type Err;
     /// Represent a value as a hex string
     fn to_hex(&self) -> String;
+    /// Represent a value as the shortest hex string which can be converted
+    /// back to the same value, eg `#fff` rather than `#ffffff`
+    fn to_hex_short(&self) -> String {
+        self.to_hex()
+    }
     /// Convert a hex string to a value
     /// # Errors
     /// Returns error if the hex string is not valid or value is out of bounds
@@ -36,10 +116,7 @@ impl Hex for u8 {
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(scaled(hex, u64::from(<Self as Channel>::MAX))? as Self)
     }
 }
 
@@ -48,14 +125,11 @@ impl Hex for u16 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        format!("{self:04x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(scaled(hex, u64::from(<Self as Channel>::MAX))? as Self)
     }
 }
 
@@ -64,14 +138,11 @@ impl Hex for u32 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        format!("{self:08x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(scaled(hex, u64::from(<Self as Channel>::MAX))? as Self)
     }
 }
 
@@ -80,14 +151,11 @@ impl Hex for u64 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        format!("{self:016x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        scaled(hex, <Self as Channel>::MAX)
     }
 }
 
@@ -96,14 +164,16 @@ impl Hex for i16 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        let value = rescale(
+            *self as u64,
+            <Self as Channel>::MAX as u64,
+            u64::from(u16::MAX),
+        );
+        format!("{value:04x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(scaled(hex, <Self as Channel>::MAX as u64)? as Self)
     }
 }
 
@@ -112,14 +182,16 @@ impl Hex for i32 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        let value = rescale(
+            *self as u64,
+            <Self as Channel>::MAX as u64,
+            u64::from(u32::MAX),
+        );
+        format!("{value:08x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(scaled(hex, <Self as Channel>::MAX as u64)? as Self)
     }
 }
 
@@ -128,14 +200,13 @@ impl Hex for i64 {
     type Err = ColorError;
 
     fn to_hex(&self) -> String {
-        format!("{self:02x}")
+        let value = rescale(*self as u64, <Self as Channel>::MAX as u64, u64::MAX);
+        format!("{value:016x}")
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match Self::from_str_radix(hex, 16) {
-            Ok(c) => Ok(c),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Self::try_from(scaled(hex, <Self as Channel>::MAX as u64)?)
+            .map_err(|_| ColorError::OutsideBoundsHigh)
     }
 }
 
@@ -148,10 +219,7 @@ impl Hex for f32 {
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match u8::from_str_radix(hex, 16) {
-            Ok(c) => Ok(f32::from(c) / 255.0),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        Ok(fraction(hex)? as Self)
     }
 }
 
@@ -164,9 +232,6 @@ impl Hex for f64 {
     }
 
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        match u8::from_str_radix(hex, 16) {
-            Ok(c) => Ok(f64::from(c) / 255.0),
-            Err(_) => Err(ColorError::InvalidHexCharacter),
-        }
+        fraction(hex)
     }
 }
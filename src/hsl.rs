@@ -0,0 +1,282 @@
+use {
+    crate::{channel, Channel, ColorError, RGB, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color as hue, saturation and lightness with no transparency.
+///
+/// The hue is stored as a fraction of a full turn, so that `Channel::MIN`
+/// is 0° and `Channel::MAX` is 360°. With integer channels the hue is
+/// quantized to the range of the type, so `Hsl<u8>` can only represent 256
+/// hues about 1.4° apart and converting to it is lossy. Converting to and
+/// from `RGB` may change the channel type, so a color stored as `RGB<u8>` can
+/// be round tripped losslessly through `Hsl<f32>` or `Hsl<f64>`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hsl<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub saturation: T,
+    pub lightness: T,
+}
+
+/// Represents a color as hue, saturation and lightness with an alpha channel
+/// for transparency
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hsla<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub saturation: T,
+    pub lightness: T,
+    pub alpha: T,
+}
+
+impl<T> Hsl<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, saturation: T, lightness: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, saturation, lightness])?;
+        Ok(Self {
+            hue,
+            saturation,
+            lightness,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, saturation: T, lightness: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            saturation: channel::clamp(saturation),
+            lightness: channel::clamp(lightness),
+        }
+    }
+}
+
+impl<T> Hsla<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, saturation: T, lightness: T, alpha: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, saturation, lightness, alpha])?;
+        Ok(Self {
+            hue,
+            saturation,
+            lightness,
+            alpha,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, saturation: T, lightness: T, alpha: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            saturation: channel::clamp(saturation),
+            lightness: channel::clamp(lightness),
+            alpha: channel::clamp(alpha),
+        }
+    }
+}
+
+impl<T> fmt::Display for Hsl<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HSL({}, {}, {})",
+            self.hue.channel_display(),
+            self.saturation.channel_display(),
+            self.lightness.channel_display(),
+        )
+    }
+}
+
+impl<T> fmt::Display for Hsla<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HSLA({}, {}, {}, {})",
+            self.hue.channel_display(),
+            self.saturation.channel_display(),
+            self.lightness.channel_display(),
+            self.alpha.channel_display(),
+        )
+    }
+}
+
+/// Calculates the hue of a color from it's red, green and blue percentages,
+/// as a fraction of a full turn in the range `0.0..1.0`
+pub(crate) fn hue(red: f32, green: f32, blue: f32) -> f32 {
+    let max = red.max(green).max(blue);
+    let delta = max - red.min(green).min(blue);
+    if delta <= 0.0 {
+        return 0.0;
+    }
+    let sixths = if (max - red).abs() < f32::EPSILON {
+        (green - blue) / delta
+    } else if (max - green).abs() < f32::EPSILON {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+    (sixths / 6.0).rem_euclid(1.0)
+}
+
+/// Converts red, green and blue percentages to hue, saturation and lightness
+pub(crate) fn from_rgb(red: f32, green: f32, blue: f32) -> (f32, f32, f32) {
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = f32::midpoint(max, min);
+    let delta = max - min;
+    let saturation = if delta <= 0.0 {
+        0.0
+    } else {
+        delta / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+    (hue(red, green, blue), saturation.min(1.0), lightness)
+}
+
+/// Converts hue, saturation and lightness to red, green and blue percentages
+pub(crate) fn to_rgb(hue: f32, saturation: f32, lightness: f32) -> (f32, f32, f32) {
+    let a = saturation * lightness.min(1.0 - lightness);
+    let f = |n: f32| {
+        let k = (n + hue * 12.0).rem_euclid(12.0);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    (f(0.0), f(8.0), f(4.0))
+}
+
+impl<T, U> From<RGB<T>> for Hsl<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        let (h, s, l) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            saturation: U::from_percent(s),
+            lightness: U::from_percent(l),
+        }
+    }
+}
+
+impl<T, U> From<Hsl<T>> for RGB<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hsl<T>) -> Self {
+        let (r, g, b) = to_rgb(
+            color.hue.to_percent(),
+            color.saturation.to_percent(),
+            color.lightness.to_percent(),
+        );
+        Self {
+            red: U::from_percent(r),
+            green: U::from_percent(g),
+            blue: U::from_percent(b),
+        }
+    }
+}
+
+impl<T, U> From<RGBA<T>> for Hsla<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        let (h, s, l) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            saturation: U::from_percent(s),
+            lightness: U::from_percent(l),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+impl<T, U> From<Hsla<T>> for RGBA<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hsla<T>) -> Self {
+        let (r, g, b) = to_rgb(
+            color.hue.to_percent(),
+            color.saturation.to_percent(),
+            color.lightness.to_percent(),
+        );
+        Self {
+            red: U::from_percent(r),
+            green: U::from_percent(g),
+            blue: U::from_percent(b),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    #[test]
+    fn primary_hues() {
+        let green: Hsl<f32> = RGB::<u8>::from(PrimaryColor::Green).into();
+        assert!((green.hue - 1.0 / 3.0).abs() < 1e-6);
+        assert!((green.saturation - 1.0).abs() < 1e-6);
+        assert!((green.lightness - 0.5).abs() < 1e-6);
+        let white: Hsl<u8> = RGB::<u8>::from(PrimaryColor::White).into();
+        assert_eq!(white, Hsl::new(0, 0, 255));
+    }
+
+    #[test]
+    fn round_trip_u8() {
+        for red in (0..=255).step_by(5) {
+            for green in (0..=255).step_by(5) {
+                for blue in (0..=255).step_by(5) {
+                    let rgba = RGBA::<u8>::new(red, green, blue, 128);
+                    let hsla: Hsla<f32> = rgba.into();
+                    assert_eq!(rgba, RGBA::<u8>::from(hsla));
+                }
+            }
+        }
+    }
+}
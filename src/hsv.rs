@@ -0,0 +1,241 @@
+use {
+    crate::{channel, hsl, Channel, ColorError, RGB, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color as hue, saturation and value with no transparency.
+///
+/// As with `Hsl`, the hue is stored as a fraction of a full turn, so that
+/// `Channel::MIN` is 0° and `Channel::MAX` is 360°. An `Hsv<u8>` can only
+/// represent 256 hues, so use a floating point channel type where the hue
+/// must survive a round trip through `RGB`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hsv<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub saturation: T,
+    pub value: T,
+}
+
+/// Represents a color as hue, saturation and value with an alpha channel for
+/// transparency
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hsva<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub saturation: T,
+    pub value: T,
+    pub alpha: T,
+}
+
+impl<T> Hsv<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, saturation: T, value: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, saturation, value])?;
+        Ok(Self {
+            hue,
+            saturation,
+            value,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, saturation: T, value: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            saturation: channel::clamp(saturation),
+            value: channel::clamp(value),
+        }
+    }
+}
+
+impl<T> Hsva<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, saturation: T, value: T, alpha: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, saturation, value, alpha])?;
+        Ok(Self {
+            hue,
+            saturation,
+            value,
+            alpha,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, saturation: T, value: T, alpha: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            saturation: channel::clamp(saturation),
+            value: channel::clamp(value),
+            alpha: channel::clamp(alpha),
+        }
+    }
+}
+
+impl<T> fmt::Display for Hsv<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HSV({}, {}, {})",
+            self.hue.channel_display(),
+            self.saturation.channel_display(),
+            self.value.channel_display(),
+        )
+    }
+}
+
+impl<T> fmt::Display for Hsva<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HSVA({}, {}, {}, {})",
+            self.hue.channel_display(),
+            self.saturation.channel_display(),
+            self.value.channel_display(),
+            self.alpha.channel_display(),
+        )
+    }
+}
+
+/// Converts red, green and blue percentages to hue, saturation and value
+pub(crate) fn from_rgb(red: f32, green: f32, blue: f32) -> (f32, f32, f32) {
+    let value = red.max(green).max(blue);
+    let delta = value - red.min(green).min(blue);
+    let saturation = if value <= 0.0 { 0.0 } else { delta / value };
+    (hsl::hue(red, green, blue), saturation, value)
+}
+
+/// Converts hue, saturation and value to red, green and blue percentages
+pub(crate) fn to_rgb(hue: f32, saturation: f32, value: f32) -> (f32, f32, f32) {
+    let f = |n: f32| {
+        let k = (n + hue * 6.0).rem_euclid(6.0);
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+    (f(5.0), f(3.0), f(1.0))
+}
+
+impl<T, U> From<RGB<T>> for Hsv<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        let (h, s, v) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            saturation: U::from_percent(s),
+            value: U::from_percent(v),
+        }
+    }
+}
+
+impl<T, U> From<Hsv<T>> for RGB<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hsv<T>) -> Self {
+        let (r, g, b) = to_rgb(
+            color.hue.to_percent(),
+            color.saturation.to_percent(),
+            color.value.to_percent(),
+        );
+        Self {
+            red: U::from_percent(r),
+            green: U::from_percent(g),
+            blue: U::from_percent(b),
+        }
+    }
+}
+
+impl<T, U> From<RGBA<T>> for Hsva<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        let (h, s, v) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            saturation: U::from_percent(s),
+            value: U::from_percent(v),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+impl<T, U> From<Hsva<T>> for RGBA<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hsva<T>) -> Self {
+        let (r, g, b) = to_rgb(
+            color.hue.to_percent(),
+            color.saturation.to_percent(),
+            color.value.to_percent(),
+        );
+        Self {
+            red: U::from_percent(r),
+            green: U::from_percent(g),
+            blue: U::from_percent(b),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    #[test]
+    fn round_trip() {
+        let orange = RGB::<u8>::new(255, 165, 0);
+        let hsv: Hsv<f64> = orange.into();
+        assert_eq!(orange, RGB::<u8>::from(hsv));
+        let yellow: Hsv<u8> = RGB::<u8>::from(PrimaryColor::Yellow).into();
+        assert_eq!(yellow, Hsv::new(43, 255, 255));
+    }
+}
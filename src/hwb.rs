@@ -0,0 +1,303 @@
+use {
+    crate::{channel, css, hsl, Channel, ColorError, RGB, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color as hue, whiteness and blackness with no transparency.
+/// A pure hue is tinted by mixing in white and shaded by mixing in black,
+/// which makes it an intuitive way to describe lighter and darker variants.
+///
+/// As with `Hsl`, the hue is stored as a fraction of a full turn, so that
+/// `Channel::MIN` is 0° and `Channel::MAX` is 360°.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hwb<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub whiteness: T,
+    pub blackness: T,
+}
+
+/// Represents a color as hue, whiteness and blackness with an alpha channel
+/// for transparency
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Hwba<T>
+where
+    T: Channel,
+{
+    pub hue: T,
+    pub whiteness: T,
+    pub blackness: T,
+    pub alpha: T,
+}
+
+impl<T> Hwb<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, whiteness: T, blackness: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, whiteness, blackness])?;
+        Ok(Self {
+            hue,
+            whiteness,
+            blackness,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, whiteness: T, blackness: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            whiteness: channel::clamp(whiteness),
+            blackness: channel::clamp(blackness),
+        }
+    }
+
+    /// Formats the color as a CSS `hwb()` function, eg `hwb(120 25% 50%)`
+    /// ```
+    /// use rgba_simple::{Hwb, RGB};
+    ///
+    /// let green: Hwb<f32> = RGB::<u8>::new(51, 204, 51).into();
+    /// assert_eq!(green.to_css(), "hwb(120 20% 20%)");
+    /// ```
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        format!(
+            "hwb({} {}% {}%)",
+            css::format_number(self.hue.to_percent() * 360.0),
+            css::format_number(self.whiteness.to_percent() * 100.0),
+            css::format_number(self.blackness.to_percent() * 100.0),
+        )
+    }
+}
+
+impl<T> Hwba<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(hue: T, whiteness: T, blackness: T, alpha: T) -> Result<Self, ColorError> {
+        channel::validate(&[hue, whiteness, blackness, alpha])?;
+        Ok(Self {
+            hue,
+            whiteness,
+            blackness,
+            alpha,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(hue: T, whiteness: T, blackness: T, alpha: T) -> Self {
+        Self {
+            hue: channel::clamp(hue),
+            whiteness: channel::clamp(whiteness),
+            blackness: channel::clamp(blackness),
+            alpha: channel::clamp(alpha),
+        }
+    }
+
+    /// Formats the color as a CSS `hwb()` function, including the alpha
+    /// channel only when the color is not fully opaque
+    #[must_use]
+    pub fn to_css(&self) -> String {
+        let color = Hwb {
+            hue: self.hue,
+            whiteness: self.whiteness,
+            blackness: self.blackness,
+        }
+        .to_css();
+        if self.alpha < T::MAX {
+            format!(
+                "{} / {})",
+                color.trim_end_matches(')'),
+                css::format_number(self.alpha.to_percent())
+            )
+        } else {
+            color
+        }
+    }
+}
+
+impl<T> fmt::Display for Hwb<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HWB({}, {}, {})",
+            self.hue.channel_display(),
+            self.whiteness.channel_display(),
+            self.blackness.channel_display(),
+        )
+    }
+}
+
+impl<T> fmt::Display for Hwba<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HWBA({}, {}, {}, {})",
+            self.hue.channel_display(),
+            self.whiteness.channel_display(),
+            self.blackness.channel_display(),
+            self.alpha.channel_display(),
+        )
+    }
+}
+
+/// Converts red, green and blue percentages to hue, whiteness and blackness
+pub(crate) fn from_rgb(red: f32, green: f32, blue: f32) -> (f32, f32, f32) {
+    let whiteness = red.min(green).min(blue);
+    let blackness = 1.0 - red.max(green).max(blue);
+    (hsl::hue(red, green, blue), whiteness, blackness)
+}
+
+/// Converts hue, whiteness and blackness to red, green and blue percentages.
+/// Where whiteness and blackness add up to more than 1.0 they are scaled
+/// down proportionally, giving a gray.
+pub(crate) fn to_rgb(hue: f32, whiteness: f32, blackness: f32) -> (f32, f32, f32) {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return (gray, gray, gray);
+    }
+    let (r, g, b) = hsl::to_rgb(hue, 1.0, 0.5);
+    let scale = |c: f32| c * (1.0 - whiteness - blackness) + whiteness;
+    (scale(r), scale(g), scale(b))
+}
+
+impl<T, U> From<RGB<T>> for Hwb<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        let (h, w, b) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            whiteness: U::from_percent(w),
+            blackness: U::from_percent(b),
+        }
+    }
+}
+
+impl<T, U> From<Hwb<T>> for RGB<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hwb<T>) -> Self {
+        let (red, green, blue) = to_rgb(
+            color.hue.to_percent(),
+            color.whiteness.to_percent(),
+            color.blackness.to_percent(),
+        );
+        Self {
+            red: U::from_percent(red),
+            green: U::from_percent(green),
+            blue: U::from_percent(blue),
+        }
+    }
+}
+
+impl<T, U> From<RGBA<T>> for Hwba<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        let (h, w, b) = from_rgb(
+            color.red.to_percent(),
+            color.green.to_percent(),
+            color.blue.to_percent(),
+        );
+        Self {
+            hue: U::from_percent(h),
+            whiteness: U::from_percent(w),
+            blackness: U::from_percent(b),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+impl<T, U> From<Hwba<T>> for RGBA<U>
+where
+    T: Channel,
+    U: Channel,
+{
+    fn from(color: Hwba<T>) -> Self {
+        let (red, green, blue) = to_rgb(
+            color.hue.to_percent(),
+            color.whiteness.to_percent(),
+            color.blackness.to_percent(),
+        );
+        Self {
+            red: U::from_percent(red),
+            green: U::from_percent(green),
+            blue: U::from_percent(blue),
+            alpha: U::from_percent(color.alpha.to_percent()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        let tint = RGB::<u8>::new(255, 128, 128);
+        let hwb: Hwb<f32> = tint.into();
+        assert!(hwb.hue.abs() < 1e-6);
+        assert!((hwb.whiteness - 128.0 / 255.0).abs() < 1e-6);
+        assert!(hwb.blackness.abs() < 1e-6);
+        assert_eq!(RGB::<u8>::from(hwb), tint);
+        let gray: RGB<u8> = Hwb::<f32>::new(0.5, 0.6, 0.6).into();
+        assert_eq!(gray, RGB::new(128, 128, 128));
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(15) {
+                let color = RGBA::<u8>::new(red, green, 60, 200);
+                assert_eq!(color, RGBA::from(Hwba::<f32>::from(color)));
+            }
+        }
+    }
+
+    #[test]
+    fn css() {
+        let blue = Hwb::<f32>::new(2.0 / 3.0, 0.2, 0.0);
+        assert_eq!(blue.to_css(), "hwb(240 20% 0%)");
+        let clear = Hwba::<u8>::new(0, 0, 255, 128);
+        assert_eq!(clear.to_css(), "hwb(0 0% 100% / 0.502)");
+        let opaque = Hwba::<u8>::new(0, 0, 255, 255);
+        assert_eq!(opaque.to_css(), "hwb(0 0% 100%)");
+        let color = RGBA::<u8>::new(200, 150, 100, 51);
+        let css = Hwba::<f32>::from(color).to_css();
+        assert_eq!(crate::parse_css::<u8>(&css), Ok(color));
+    }
+}
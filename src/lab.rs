@@ -0,0 +1,280 @@
+use {
+    crate::{Channel, WhitePoint, Xyz, RGB, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const KAPPA: f32 = 24389.0 / 27.0;
+const EPSILON: f32 = 216.0 / 24389.0;
+
+/// Represents a color in the CIELAB color space, with lightness ranging from
+/// 0.0 to 100.0. Conversions to and from other types use the D65 white point
+/// unless another is given explicitly.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Lab {
+    pub lightness: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// Represents a color in the cylindrical form of CIELAB, with chroma in
+/// place of `a` and `b` and the hue in degrees
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Lch {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+impl Lab {
+    /// Converts from XYZ, where both the color and the resulting Lab value
+    /// are relative to the given white point
+    #[must_use]
+    pub fn from_xyz(xyz: Xyz, white: WhitePoint) -> Self {
+        let reference = white.xyz();
+        let f = |t: f32| {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let fx = f(xyz.x / reference.x);
+        let fy = f(xyz.y / reference.y);
+        let fz = f(xyz.z / reference.z);
+        Self {
+            lightness: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    /// Converts to XYZ relative to the given white point
+    #[must_use]
+    pub fn to_xyz(&self, white: WhitePoint) -> Xyz {
+        let reference = white.xyz();
+        let fy = (self.lightness + 16.0) / 116.0;
+        let fx = self.a / 500.0 + fy;
+        let fz = fy - self.b / 200.0;
+        let f_inv = |f: f32| {
+            if f.powi(3) > EPSILON {
+                f.powi(3)
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if self.lightness > KAPPA * EPSILON {
+            fy.powi(3)
+        } else {
+            self.lightness / KAPPA
+        };
+        Xyz {
+            x: f_inv(fx) * reference.x,
+            y: y * reference.y,
+            z: f_inv(fz) * reference.z,
+        }
+    }
+
+    /// Converts an sRGB color to Lab relative to the given white point
+    pub fn from_rgb<T>(color: RGB<T>, white: WhitePoint) -> Self
+    where
+        T: Channel,
+    {
+        Self::from_xyz(Xyz::from(color).adapt(WhitePoint::D65, white), white)
+    }
+
+    /// Converts to sRGB from Lab relative to the given white point, clipping
+    /// any channels which are out of gamut
+    #[must_use]
+    pub fn to_rgb<T>(&self, white: WhitePoint) -> RGB<T>
+    where
+        T: Channel,
+    {
+        self.to_xyz(white).adapt(white, WhitePoint::D65).into()
+    }
+}
+
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Lab({:.3}, {:.3}, {:.3})",
+            self.lightness, self.a, self.b
+        )
+    }
+}
+
+impl fmt::Display for Lch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LCh({:.3}, {:.3}, {:.3})",
+            self.lightness, self.chroma, self.hue
+        )
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(color: Lab) -> Self {
+        Self {
+            lightness: color.lightness,
+            chroma: color.a.hypot(color.b),
+            hue: color.b.atan2(color.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(color: Lch) -> Self {
+        let (sin, cos) = color.hue.to_radians().sin_cos();
+        let chroma = color.chroma.max(0.0);
+        Self {
+            lightness: color.lightness,
+            a: chroma * cos,
+            b: chroma * sin,
+        }
+    }
+}
+
+impl From<Xyz> for Lab {
+    fn from(color: Xyz) -> Self {
+        Self::from_xyz(color, WhitePoint::D65)
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(color: Lab) -> Self {
+        color.to_xyz(WhitePoint::D65)
+    }
+}
+
+impl<T> From<RGB<T>> for Lab
+where
+    T: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl<T> From<RGBA<T>> for Lab
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl<T> From<Lab> for RGB<T>
+where
+    T: Channel,
+{
+    fn from(color: Lab) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl<T> From<Lab> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: Lab) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl<T> From<RGB<T>> for Lch
+where
+    T: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+impl<T> From<RGBA<T>> for Lch
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+impl<T> From<Lch> for RGB<T>
+where
+    T: Channel,
+{
+    fn from(color: Lch) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+impl<T> From<Lch> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: Lch) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 0.01, "{a} != {b}");
+    }
+
+    #[test]
+    fn red_d65() {
+        let lab: Lab = RGB::<u8>::from(PrimaryColor::Red).into();
+        assert_close(lab.lightness, 53.24);
+        assert_close(lab.a, 80.09);
+        assert_close(lab.b, 67.2);
+        let lch: Lch = lab.into();
+        assert_close(lch.chroma, 104.55);
+        assert_close(lch.hue, 40.0);
+    }
+
+    #[test]
+    fn red_d50() {
+        let lab = Lab::from_rgb(RGB::<u8>::from(PrimaryColor::Red), WhitePoint::D50);
+        assert_close(lab.lightness, 54.29);
+        assert_close(lab.a, 80.8);
+        assert_close(lab.b, 69.89);
+        assert_eq!(lab.to_rgb::<u8>(WhitePoint::D50), PrimaryColor::Red.into());
+    }
+
+    #[test]
+    fn white() {
+        let white: Lab = RGB::<u8>::from(PrimaryColor::White).into();
+        assert_close(white.lightness, 100.0);
+        assert_close(white.a, 0.0);
+        assert_close(white.b, 0.0);
+        let xyz: Xyz = RGB::<u8>::from(PrimaryColor::White).into();
+        assert_close(xyz.x, WhitePoint::D65.xyz().x);
+        assert_close(xyz.z, WhitePoint::D65.xyz().z);
+    }
+
+    #[test]
+    fn round_trip() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(15) {
+                for blue in (0..=255).step_by(15) {
+                    let color = RGB::<u8>::new(red, green, blue);
+                    assert_eq!(color, RGB::from(Lch::from(color)));
+                }
+            }
+        }
+    }
+}
@@ -3,20 +3,63 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_precision_loss)]
 #![doc = include_str!("../README.md")]
+mod apca;
+pub use apca::{apca_contrast, apca_font_size};
+mod blend;
+pub use blend::BlendMode;
 mod channel;
 pub(crate) use channel::Channel;
+pub mod chromatic_adaptation;
+mod cmyk;
+pub use cmyk::{Cmyk, CmykOptions};
 mod colorerror;
 pub use colorerror::ColorError;
+mod composite;
+pub use composite::CompositeOperator;
+mod contrast;
+pub use contrast::{contrast_ratio, TextSize, WcagLevel};
+mod css;
+pub use css::parse_css;
+pub mod delta_e;
+mod gamut;
+pub use gamut::{GamutMap, GamutMapMode};
 mod hex;
 pub use hex::Hex;
+mod hsl;
+pub use hsl::{Hsl, Hsla};
+mod hsv;
+pub use hsv::{Hsv, Hsva};
+mod hwb;
+pub use hwb::{Hwb, Hwba};
+mod lab;
+pub use lab::{Lab, Lch};
+mod linear;
+pub use linear::{srgb_decode, srgb_encode, LinearRGBA};
+mod matrix;
+mod named;
+pub use named::{NamedColor, CSS_COLORS};
+mod oklab;
+pub use oklab::{Oklab, Oklch};
+mod premultiplied;
+pub use premultiplied::PremultipliedRGBA;
 mod rgb;
 pub use rgb::RGB;
 mod rgba;
 pub use rgba::RGBA;
+mod space;
+pub use space::{AdobeRgb, DisplayP3, ProPhotoRgb, Rec2020, RgbColorSpace, Srgb, TaggedRGBA};
+mod temperature;
+mod x11;
+pub use x11::{parse_x11, X11_COLORS};
+mod xyz;
+pub use xyz::{WhitePoint, Xyz};
+mod ycbcr;
+pub use ycbcr::{MatrixCoefficients, QuantizationRange, YCbCr};
 #[cfg(feature = "gdk")]
 mod gdk_impl;
 
-/// An enumeration of primary and secondary colors
+/// An enumeration of primary and secondary colors. See `NamedColor` for the
+/// full set of CSS named colors.
 pub enum PrimaryColor {
     Black,
     White,
@@ -59,4 +102,67 @@ mod tests {
         let blue: RGB<f64> = RGB::from(PrimaryColor::Blue);
         assert_eq!(blue.to_hex(), blue_hex);
     }
+
+    #[test]
+    fn hex_alpha() {
+        let translucent = RGBA::<u8>::new(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(translucent.to_hex(), "#11223344");
+        assert_eq!(translucent.to_hex_short(), "#1234");
+        assert_eq!(RGBA::<u8>::from_hex("#1234"), Ok(translucent));
+        assert_eq!(RGBA::<u8>::from_hex("#11223344"), Ok(translucent));
+        let opaque = RGBA::<f32>::from_hex("#fa0").unwrap();
+        assert_eq!(opaque.to_hex(), "#ffaa00");
+        assert_eq!(opaque.to_hex_short(), "#fa0");
+        assert_eq!(
+            RGB::<u8>::from_hex("#11223344"),
+            Err(ColorError::HexStringOverflow)
+        );
+        assert_eq!(
+            RGBA::<u8>::from_hex("#11223"),
+            Err(ColorError::TruncatedHexString)
+        );
+    }
+
+    #[test]
+    fn hex_16_bit() {
+        let color = RGB::<u16>::new(0x1234, 0xabcd, 0xffff);
+        assert_eq!(color.to_hex(), "#1234abcdffff");
+        assert_eq!(RGB::<u16>::from_hex("#1234abcdffff"), Ok(color));
+        assert_eq!(
+            RGB::<u16>::from_hex("#ff8000"),
+            Ok(RGB::new(0xffff, 0x8080, 0))
+        );
+        assert_eq!(
+            RGBA::<u8>::from_hex("#ffff80800000"),
+            Ok(RGBA::new(0xff, 0x80, 0, 0xff))
+        );
+        let signed = RGBA::<i16>::new(0, 1, 16384, i16::MAX);
+        assert_eq!(RGBA::<i16>::from_hex(&signed.to_hex()), Ok(signed));
+        let blue: RGB<u16> = PrimaryColor::Blue.into();
+        assert_eq!(blue.to_hex_short(), "#00f");
+    }
+
+    #[test]
+    fn convert() {
+        for value in 0..=255 {
+            let color = RGBA::<u8>::new(value, 255 - value, value / 2, value);
+            assert_eq!(color.convert::<f32>().convert::<u8>(), color);
+            assert_eq!(color.convert::<u16>().convert::<u8>(), color);
+        }
+        let gray = RGB::<u8>::new(0x80, 0x80, 0x80).convert::<u16>();
+        assert_eq!(gray, RGB::new(0x8080, 0x8080, 0x8080));
+        let half = RGB::<f64>::new(0.5, 0.5, 0.5).convert::<u8>();
+        assert_eq!(half, RGB::new(128, 128, 128));
+        let precise = RGB::<f64>::new(0.1, 1.0 / 3.0, 0.987_654_321);
+        assert_eq!(precise.convert::<f64>(), precise);
+        let deep = RGBA::<u32>::new(0x1234_5678, u32::MAX, 1, 0x8000_0000);
+        assert_eq!(deep.convert::<u64>().convert::<u32>(), deep);
+        assert_eq!(deep.convert::<f64>().convert::<u32>(), deep);
+        assert_eq!(
+            RGB::<u8>::new(0xab, 0, 0xff).convert::<u64>(),
+            RGB::new(0xabab_abab_abab_abab, 0, u64::MAX)
+        );
+        let wide = RGB::<u64>::new(u64::MAX - 1, 1, 0x0123_4567_89ab_cdef);
+        assert_eq!(wide.convert::<u64>(), wide);
+    }
 }
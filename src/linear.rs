@@ -0,0 +1,165 @@
+use {
+    crate::{channel, Channel, ColorError, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color as red, green and blue channels in linear light, with an
+/// alpha channel for transparency.
+///
+/// `RGBA` stores gamma encoded sRGB values, which is what displays and most
+/// file formats expect, but blending, scaling and luminance calculations are
+/// only correct when performed on linear values. Keeping those values in a
+/// separate type prevents the two from being mixed up. As linear values need
+/// more precision in the darker range than gamma encoded values, a floating
+/// point channel type should be preferred.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct LinearRGBA<T>
+where
+    T: Channel,
+{
+    pub red: T,
+    pub green: T,
+    pub blue: T,
+    pub alpha: T,
+}
+
+impl<T> LinearRGBA<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(red: T, green: T, blue: T, alpha: T) -> Result<Self, ColorError> {
+        channel::validate(&[red, green, blue, alpha])?;
+        Ok(Self {
+            red,
+            green,
+            blue,
+            alpha,
+        })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(red: T, green: T, blue: T, alpha: T) -> Self {
+        Self {
+            red: channel::clamp(red),
+            green: channel::clamp(green),
+            blue: channel::clamp(blue),
+            alpha: channel::clamp(alpha),
+        }
+    }
+
+    /// Gamma encodes the color channels using the sRGB transfer function. The
+    /// alpha channel is unchanged.
+    pub fn to_srgb(&self) -> RGBA<T> {
+        RGBA {
+            red: T::from_percent(srgb_encode(self.red.to_percent())),
+            green: T::from_percent(srgb_encode(self.green.to_percent())),
+            blue: T::from_percent(srgb_encode(self.blue.to_percent())),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T> RGBA<T>
+where
+    T: Channel,
+{
+    /// Decodes the color channels to linear light using the inverse of the
+    /// sRGB transfer function. The alpha channel is unchanged.
+    pub fn to_linear(&self) -> LinearRGBA<T> {
+        LinearRGBA {
+            red: T::from_percent(srgb_decode(self.red.to_percent())),
+            green: T::from_percent(srgb_decode(self.green.to_percent())),
+            blue: T::from_percent(srgb_decode(self.blue.to_percent())),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T> fmt::Display for LinearRGBA<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LinearRGBA({}, {}, {}, {})",
+            self.red.channel_display(),
+            self.green.channel_display(),
+            self.blue.channel_display(),
+            self.alpha.channel_display(),
+        )
+    }
+}
+
+impl<T> From<RGBA<T>> for LinearRGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        color.to_linear()
+    }
+}
+
+impl<T> From<LinearRGBA<T>> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: LinearRGBA<T>) -> Self {
+        color.to_srgb()
+    }
+}
+
+/// The sRGB transfer function (OETF), converting a linear light value to it's
+/// gamma encoded form. Negative values are mirrored around zero.
+#[must_use]
+pub fn srgb_encode(value: f32) -> f32 {
+    if value.abs() > 0.003_130_8 {
+        value.signum() * (1.055 * value.abs().powf(1.0 / 2.4) - 0.055)
+    } else {
+        value * 12.92
+    }
+}
+
+/// The inverse of the sRGB transfer function (EOTF), converting a gamma
+/// encoded value to linear light. Negative values are mirrored around zero.
+#[must_use]
+pub fn srgb_decode(value: f32) -> f32 {
+    if value.abs() > 0.040_45 {
+        value.signum() * ((value.abs() + 0.055) / 1.055).powf(2.4)
+    } else {
+        value / 12.92
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer() {
+        assert!((srgb_decode(0.5) - 0.214_041).abs() < 1e-6);
+        assert!((srgb_encode(0.214_041) - 0.5).abs() < 1e-6);
+        assert!((srgb_decode(0.04) - 0.04 / 12.92).abs() < f32::EPSILON);
+        assert!((srgb_encode(-0.214_041) + 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn round_trip() {
+        for value in 0..=255 {
+            let color = RGBA::<u8>::new(value, value, value, value);
+            let linear = color.convert::<f32>().to_linear();
+            assert_eq!(linear.to_srgb().convert::<u8>(), color);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/// A 3x3 matrix in row major order, used for linear transformations between
+/// color spaces
+pub(crate) type Matrix = [[f32; 3]; 3];
+
+/// Multiplies a column vector by a matrix
+pub(crate) fn transform(matrix: &Matrix, vector: [f32; 3]) -> [f32; 3] {
+    let mut out = [0.0; 3];
+    for (o, row) in out.iter_mut().zip(matrix) {
+        *o = row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+    }
+    out
+}
+
+/// Multiplies two matrices
+pub(crate) fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    out
+}
+
+/// Creates a matrix which scales each component of a vector independently
+pub(crate) fn diagonal(scale: [f32; 3]) -> Matrix {
+    [
+        [scale[0], 0.0, 0.0],
+        [0.0, scale[1], 0.0],
+        [0.0, 0.0, scale[2]],
+    ]
+}
+
+/// Calculates the inverse of a matrix. The matrices used for color space
+/// conversions are always invertible.
+pub(crate) fn invert(m: &Matrix) -> Matrix {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let adjugate = [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            -cofactor(1, 2, 0, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 1, 0, 2),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            -cofactor(0, 2, 0, 1),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+    adjugate.map(|row| row.map(|x| x / determinant))
+}
@@ -0,0 +1,335 @@
+use {
+    crate::{delta_e, Channel, ColorError, Lab, RGB, RGBA},
+    std::{fmt, str::FromStr},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+macro_rules! named_colors {
+    ($($variant:ident => $name:literal, $red:literal, $green:literal, $blue:literal;)*) => {
+        /// The 148 named colors of CSS Color Module Level 4, which are shared
+        /// with SVG and most other web technologies
+        /// ```
+        /// use rgba_simple::{NamedColor, RGB};
+        ///
+        /// let purple: NamedColor = "RebeccaPurple".parse().unwrap();
+        /// assert_eq!(RGB::<u8>::from(purple), RGB::new(102, 51, 153));
+        /// assert_eq!(NamedColor::from_rgb(RGB::new(100, 149, 237)).unwrap().name(), "cornflowerblue");
+        /// ```
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+        pub enum NamedColor {
+            $(
+                #[doc = concat!("The CSS color `", $name, "`")]
+                $variant,
+            )*
+        }
+
+        impl NamedColor {
+            /// Every named color, in alphabetical order
+            pub const ALL: [Self; 148] = [$(Self::$variant),*];
+        }
+
+        /// The names and values of every CSS named color, in alphabetical order
+        pub const CSS_COLORS: [(&str, RGB<u8>); 148] = [
+            $(($name, RGB { red: $red, green: $green, blue: $blue }),)*
+        ];
+    };
+}
+
+named_colors! {
+    AliceBlue => "aliceblue", 0xf0, 0xf8, 0xff;
+    AntiqueWhite => "antiquewhite", 0xfa, 0xeb, 0xd7;
+    Aqua => "aqua", 0x00, 0xff, 0xff;
+    Aquamarine => "aquamarine", 0x7f, 0xff, 0xd4;
+    Azure => "azure", 0xf0, 0xff, 0xff;
+    Beige => "beige", 0xf5, 0xf5, 0xdc;
+    Bisque => "bisque", 0xff, 0xe4, 0xc4;
+    Black => "black", 0x00, 0x00, 0x00;
+    BlanchedAlmond => "blanchedalmond", 0xff, 0xeb, 0xcd;
+    Blue => "blue", 0x00, 0x00, 0xff;
+    BlueViolet => "blueviolet", 0x8a, 0x2b, 0xe2;
+    Brown => "brown", 0xa5, 0x2a, 0x2a;
+    BurlyWood => "burlywood", 0xde, 0xb8, 0x87;
+    CadetBlue => "cadetblue", 0x5f, 0x9e, 0xa0;
+    Chartreuse => "chartreuse", 0x7f, 0xff, 0x00;
+    Chocolate => "chocolate", 0xd2, 0x69, 0x1e;
+    Coral => "coral", 0xff, 0x7f, 0x50;
+    CornflowerBlue => "cornflowerblue", 0x64, 0x95, 0xed;
+    Cornsilk => "cornsilk", 0xff, 0xf8, 0xdc;
+    Crimson => "crimson", 0xdc, 0x14, 0x3c;
+    Cyan => "cyan", 0x00, 0xff, 0xff;
+    DarkBlue => "darkblue", 0x00, 0x00, 0x8b;
+    DarkCyan => "darkcyan", 0x00, 0x8b, 0x8b;
+    DarkGoldenrod => "darkgoldenrod", 0xb8, 0x86, 0x0b;
+    DarkGray => "darkgray", 0xa9, 0xa9, 0xa9;
+    DarkGreen => "darkgreen", 0x00, 0x64, 0x00;
+    DarkGrey => "darkgrey", 0xa9, 0xa9, 0xa9;
+    DarkKhaki => "darkkhaki", 0xbd, 0xb7, 0x6b;
+    DarkMagenta => "darkmagenta", 0x8b, 0x00, 0x8b;
+    DarkOliveGreen => "darkolivegreen", 0x55, 0x6b, 0x2f;
+    DarkOrange => "darkorange", 0xff, 0x8c, 0x00;
+    DarkOrchid => "darkorchid", 0x99, 0x32, 0xcc;
+    DarkRed => "darkred", 0x8b, 0x00, 0x00;
+    DarkSalmon => "darksalmon", 0xe9, 0x96, 0x7a;
+    DarkSeaGreen => "darkseagreen", 0x8f, 0xbc, 0x8f;
+    DarkSlateBlue => "darkslateblue", 0x48, 0x3d, 0x8b;
+    DarkSlateGray => "darkslategray", 0x2f, 0x4f, 0x4f;
+    DarkSlateGrey => "darkslategrey", 0x2f, 0x4f, 0x4f;
+    DarkTurquoise => "darkturquoise", 0x00, 0xce, 0xd1;
+    DarkViolet => "darkviolet", 0x94, 0x00, 0xd3;
+    DeepPink => "deeppink", 0xff, 0x14, 0x93;
+    DeepSkyBlue => "deepskyblue", 0x00, 0xbf, 0xff;
+    DimGray => "dimgray", 0x69, 0x69, 0x69;
+    DimGrey => "dimgrey", 0x69, 0x69, 0x69;
+    DodgerBlue => "dodgerblue", 0x1e, 0x90, 0xff;
+    Firebrick => "firebrick", 0xb2, 0x22, 0x22;
+    FloralWhite => "floralwhite", 0xff, 0xfa, 0xf0;
+    ForestGreen => "forestgreen", 0x22, 0x8b, 0x22;
+    Fuchsia => "fuchsia", 0xff, 0x00, 0xff;
+    Gainsboro => "gainsboro", 0xdc, 0xdc, 0xdc;
+    GhostWhite => "ghostwhite", 0xf8, 0xf8, 0xff;
+    Gold => "gold", 0xff, 0xd7, 0x00;
+    Goldenrod => "goldenrod", 0xda, 0xa5, 0x20;
+    Gray => "gray", 0x80, 0x80, 0x80;
+    Green => "green", 0x00, 0x80, 0x00;
+    GreenYellow => "greenyellow", 0xad, 0xff, 0x2f;
+    Grey => "grey", 0x80, 0x80, 0x80;
+    Honeydew => "honeydew", 0xf0, 0xff, 0xf0;
+    HotPink => "hotpink", 0xff, 0x69, 0xb4;
+    IndianRed => "indianred", 0xcd, 0x5c, 0x5c;
+    Indigo => "indigo", 0x4b, 0x00, 0x82;
+    Ivory => "ivory", 0xff, 0xff, 0xf0;
+    Khaki => "khaki", 0xf0, 0xe6, 0x8c;
+    Lavender => "lavender", 0xe6, 0xe6, 0xfa;
+    LavenderBlush => "lavenderblush", 0xff, 0xf0, 0xf5;
+    LawnGreen => "lawngreen", 0x7c, 0xfc, 0x00;
+    LemonChiffon => "lemonchiffon", 0xff, 0xfa, 0xcd;
+    LightBlue => "lightblue", 0xad, 0xd8, 0xe6;
+    LightCoral => "lightcoral", 0xf0, 0x80, 0x80;
+    LightCyan => "lightcyan", 0xe0, 0xff, 0xff;
+    LightGoldenrodYellow => "lightgoldenrodyellow", 0xfa, 0xfa, 0xd2;
+    LightGray => "lightgray", 0xd3, 0xd3, 0xd3;
+    LightGreen => "lightgreen", 0x90, 0xee, 0x90;
+    LightGrey => "lightgrey", 0xd3, 0xd3, 0xd3;
+    LightPink => "lightpink", 0xff, 0xb6, 0xc1;
+    LightSalmon => "lightsalmon", 0xff, 0xa0, 0x7a;
+    LightSeaGreen => "lightseagreen", 0x20, 0xb2, 0xaa;
+    LightSkyBlue => "lightskyblue", 0x87, 0xce, 0xfa;
+    LightSlateGray => "lightslategray", 0x77, 0x88, 0x99;
+    LightSlateGrey => "lightslategrey", 0x77, 0x88, 0x99;
+    LightSteelBlue => "lightsteelblue", 0xb0, 0xc4, 0xde;
+    LightYellow => "lightyellow", 0xff, 0xff, 0xe0;
+    Lime => "lime", 0x00, 0xff, 0x00;
+    LimeGreen => "limegreen", 0x32, 0xcd, 0x32;
+    Linen => "linen", 0xfa, 0xf0, 0xe6;
+    Magenta => "magenta", 0xff, 0x00, 0xff;
+    Maroon => "maroon", 0x80, 0x00, 0x00;
+    MediumAquamarine => "mediumaquamarine", 0x66, 0xcd, 0xaa;
+    MediumBlue => "mediumblue", 0x00, 0x00, 0xcd;
+    MediumOrchid => "mediumorchid", 0xba, 0x55, 0xd3;
+    MediumPurple => "mediumpurple", 0x93, 0x70, 0xdb;
+    MediumSeaGreen => "mediumseagreen", 0x3c, 0xb3, 0x71;
+    MediumSlateBlue => "mediumslateblue", 0x7b, 0x68, 0xee;
+    MediumSpringGreen => "mediumspringgreen", 0x00, 0xfa, 0x9a;
+    MediumTurquoise => "mediumturquoise", 0x48, 0xd1, 0xcc;
+    MediumVioletRed => "mediumvioletred", 0xc7, 0x15, 0x85;
+    MidnightBlue => "midnightblue", 0x19, 0x19, 0x70;
+    MintCream => "mintcream", 0xf5, 0xff, 0xfa;
+    MistyRose => "mistyrose", 0xff, 0xe4, 0xe1;
+    Moccasin => "moccasin", 0xff, 0xe4, 0xb5;
+    NavajoWhite => "navajowhite", 0xff, 0xde, 0xad;
+    Navy => "navy", 0x00, 0x00, 0x80;
+    OldLace => "oldlace", 0xfd, 0xf5, 0xe6;
+    Olive => "olive", 0x80, 0x80, 0x00;
+    OliveDrab => "olivedrab", 0x6b, 0x8e, 0x23;
+    Orange => "orange", 0xff, 0xa5, 0x00;
+    OrangeRed => "orangered", 0xff, 0x45, 0x00;
+    Orchid => "orchid", 0xda, 0x70, 0xd6;
+    PaleGoldenrod => "palegoldenrod", 0xee, 0xe8, 0xaa;
+    PaleGreen => "palegreen", 0x98, 0xfb, 0x98;
+    PaleTurquoise => "paleturquoise", 0xaf, 0xee, 0xee;
+    PaleVioletRed => "palevioletred", 0xdb, 0x70, 0x93;
+    PapayaWhip => "papayawhip", 0xff, 0xef, 0xd5;
+    PeachPuff => "peachpuff", 0xff, 0xda, 0xb9;
+    Peru => "peru", 0xcd, 0x85, 0x3f;
+    Pink => "pink", 0xff, 0xc0, 0xcb;
+    Plum => "plum", 0xdd, 0xa0, 0xdd;
+    PowderBlue => "powderblue", 0xb0, 0xe0, 0xe6;
+    Purple => "purple", 0x80, 0x00, 0x80;
+    RebeccaPurple => "rebeccapurple", 0x66, 0x33, 0x99;
+    Red => "red", 0xff, 0x00, 0x00;
+    RosyBrown => "rosybrown", 0xbc, 0x8f, 0x8f;
+    RoyalBlue => "royalblue", 0x41, 0x69, 0xe1;
+    SaddleBrown => "saddlebrown", 0x8b, 0x45, 0x13;
+    Salmon => "salmon", 0xfa, 0x80, 0x72;
+    SandyBrown => "sandybrown", 0xf4, 0xa4, 0x60;
+    SeaGreen => "seagreen", 0x2e, 0x8b, 0x57;
+    Seashell => "seashell", 0xff, 0xf5, 0xee;
+    Sienna => "sienna", 0xa0, 0x52, 0x2d;
+    Silver => "silver", 0xc0, 0xc0, 0xc0;
+    SkyBlue => "skyblue", 0x87, 0xce, 0xeb;
+    SlateBlue => "slateblue", 0x6a, 0x5a, 0xcd;
+    SlateGray => "slategray", 0x70, 0x80, 0x90;
+    SlateGrey => "slategrey", 0x70, 0x80, 0x90;
+    Snow => "snow", 0xff, 0xfa, 0xfa;
+    SpringGreen => "springgreen", 0x00, 0xff, 0x7f;
+    SteelBlue => "steelblue", 0x46, 0x82, 0xb4;
+    Tan => "tan", 0xd2, 0xb4, 0x8c;
+    Teal => "teal", 0x00, 0x80, 0x80;
+    Thistle => "thistle", 0xd8, 0xbf, 0xd8;
+    Tomato => "tomato", 0xff, 0x63, 0x47;
+    Turquoise => "turquoise", 0x40, 0xe0, 0xd0;
+    Violet => "violet", 0xee, 0x82, 0xee;
+    Wheat => "wheat", 0xf5, 0xde, 0xb3;
+    White => "white", 0xff, 0xff, 0xff;
+    WhiteSmoke => "whitesmoke", 0xf5, 0xf5, 0xf5;
+    Yellow => "yellow", 0xff, 0xff, 0x00;
+    YellowGreen => "yellowgreen", 0x9a, 0xcd, 0x32;
+}
+
+impl NamedColor {
+    /// The lowercase CSS name of this color
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        CSS_COLORS[self as usize].0
+    }
+
+    /// Finds a named color by it's name, ignoring case
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        CSS_COLORS
+            .iter()
+            .position(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|i| Self::ALL[i])
+    }
+
+    /// Finds the named color with exactly this value. Where several names
+    /// share a value, such as `aqua` and `cyan`, the first in alphabetical
+    /// order is returned.
+    #[must_use]
+    pub fn from_rgb(color: RGB<u8>) -> Option<Self> {
+        CSS_COLORS
+            .iter()
+            .position(|(_, value)| *value == color)
+            .map(|i| Self::ALL[i])
+    }
+
+    /// Iterates over every named color in alphabetical order
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl fmt::Display for NamedColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for NamedColor {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s.trim()).ok_or_else(|| ColorError::UnknownColorName(s.to_string()))
+    }
+}
+
+impl<T> RGB<T>
+where
+    T: Channel,
+{
+    /// Finds the entry in a table of named colors which looks closest to this
+    /// color, along with the CIEDE2000 difference between them. The table may
+    /// be `CSS_COLORS` or any other palette. Returns `None` if the table is
+    /// empty.
+    /// ```
+    /// use rgba_simple::{RGB, CSS_COLORS};
+    ///
+    /// let (name, distance) = RGB::<u8>::new(250, 130, 110).nearest_name(&CSS_COLORS).unwrap();
+    /// assert_eq!(name, "salmon");
+    /// assert!(distance < 2.0);
+    /// ```
+    #[must_use]
+    pub fn nearest_name<'a>(&self, table: &[(&'a str, RGB<u8>)]) -> Option<(&'a str, f32)> {
+        let lab = Lab::from(*self);
+        table
+            .iter()
+            .map(|(name, color)| (*name, delta_e::ciede2000(lab, *color)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+impl<T> From<NamedColor> for RGB<T>
+where
+    T: Channel,
+{
+    fn from(color: NamedColor) -> Self {
+        CSS_COLORS[color as usize].1.convert()
+    }
+}
+
+impl<T> From<NamedColor> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: NamedColor) -> Self {
+        RGB::<T>::from(color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(
+            NamedColor::from_name("CornflowerBlue"),
+            Some(NamedColor::CornflowerBlue)
+        );
+        assert_eq!(NamedColor::from_name("cornflower blue"), None);
+        assert_eq!("grey".parse(), Ok(NamedColor::Grey));
+        assert_eq!(
+            "blurple".parse::<NamedColor>(),
+            Err(ColorError::UnknownColorName("blurple".to_string()))
+        );
+        assert_eq!(
+            NamedColor::from_rgb(RGB::new(0, 255, 255)),
+            Some(NamedColor::Aqua)
+        );
+        assert_eq!(NamedColor::from_rgb(RGB::new(1, 2, 3)), None);
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(NamedColor::iter().count(), 148);
+        for color in NamedColor::iter() {
+            assert_eq!(NamedColor::from_name(color.name()), Some(color));
+            assert_eq!(color.to_string(), color.name());
+        }
+        let white: RGBA<f32> = NamedColor::White.into();
+        assert_eq!(white, RGBA::new(1.0, 1.0, 1.0, 1.0));
+    }
+
+    #[test]
+    fn nearest() {
+        for (name, color) in CSS_COLORS {
+            let (nearest, distance) = color.nearest_name(&CSS_COLORS).unwrap();
+            assert!(distance < 1e-3);
+            assert_eq!(
+                CSS_COLORS.iter().find(|(n, _)| *n == nearest).unwrap().1,
+                color,
+                "{name}"
+            );
+        }
+        let palette = [
+            ("light", RGB::new(240, 240, 240)),
+            ("dark", RGB::new(20, 20, 20)),
+        ];
+        let gray = RGB::<f32>::new(0.3, 0.3, 0.3);
+        assert_eq!(gray.nearest_name(&palette).unwrap().0, "dark");
+        assert_eq!(gray.nearest_name(&[]), None);
+    }
+}
@@ -0,0 +1,243 @@
+use {
+    crate::{
+        linear,
+        matrix::{self, Matrix},
+        Channel, RGB, RGBA,
+    },
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color in the Oklab perceptual color space, with lightness
+/// ranging from 0.0 to 1.0. Equal steps in Oklab are seen as roughly equal
+/// changes in color, which makes it well suited to generating gradients,
+/// tints and shades.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Oklab {
+    pub lightness: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// Represents a color in the cylindrical form of Oklab, with chroma in place
+/// of `a` and `b` and the hue in degrees. Changing the lightness or chroma
+/// leaves the perceived hue unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Oklch {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+const LINEAR_SRGB_TO_LMS: Matrix = [
+    [0.412_221_46, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_185, -1.291_485_5],
+];
+
+const LMS_TO_LINEAR_SRGB: Matrix = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_4, -0.703_418_6, 1.707_614_7],
+];
+
+impl Oklab {
+    /// Converts a color in linear sRGB to Oklab
+    pub(crate) fn from_linear_srgb(rgb: [f32; 3]) -> Self {
+        let lms = matrix::transform(&LINEAR_SRGB_TO_LMS, rgb).map(f32::cbrt);
+        let [lightness, a, b] = matrix::transform(&LMS_TO_OKLAB, lms);
+        Self { lightness, a, b }
+    }
+
+    /// Converts to linear sRGB without clipping to the sRGB gamut
+    pub(crate) fn to_linear_srgb(self) -> [f32; 3] {
+        let lms =
+            matrix::transform(&OKLAB_TO_LMS, [self.lightness, self.a, self.b]).map(|x| x.powi(3));
+        matrix::transform(&LMS_TO_LINEAR_SRGB, lms)
+    }
+
+    /// Converts to gamma encoded sRGB percentages, clipped to the sRGB gamut
+    pub(crate) fn to_srgb(self) -> [f32; 3] {
+        self.to_linear_srgb()
+            .map(|c| linear::srgb_encode(c).clamp(0.0, 1.0))
+    }
+}
+
+impl fmt::Display for Oklab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Oklab({:.3}, {:.3}, {:.3})",
+            self.lightness, self.a, self.b
+        )
+    }
+}
+
+impl fmt::Display for Oklch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Oklch({:.3}, {:.3}, {:.3})",
+            self.lightness, self.chroma, self.hue
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color: Oklab) -> Self {
+        Self {
+            lightness: color.lightness,
+            chroma: color.a.hypot(color.b),
+            hue: color.b.atan2(color.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        let (sin, cos) = color.hue.to_radians().sin_cos();
+        let chroma = color.chroma.max(0.0);
+        Self {
+            lightness: color.lightness,
+            a: chroma * cos,
+            b: chroma * sin,
+        }
+    }
+}
+
+impl<T> From<RGB<T>> for Oklab
+where
+    T: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        Self::from_linear_srgb(
+            [color.red, color.green, color.blue].map(|c| linear::srgb_decode(c.to_percent())),
+        )
+    }
+}
+
+impl<T> From<RGBA<T>> for Oklab
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Self::from_linear_srgb(
+            [color.red, color.green, color.blue].map(|c| linear::srgb_decode(c.to_percent())),
+        )
+    }
+}
+
+impl<T> From<Oklab> for RGB<T>
+where
+    T: Channel,
+{
+    /// Converts to sRGB, clipping any channels which are out of gamut
+    fn from(color: Oklab) -> Self {
+        let [red, green, blue] = color.to_srgb();
+        Self {
+            red: T::from_percent(red),
+            green: T::from_percent(green),
+            blue: T::from_percent(blue),
+        }
+    }
+}
+
+impl<T> From<Oklab> for RGBA<T>
+where
+    T: Channel,
+{
+    /// Converts to a fully opaque sRGB color, clipping any channels which
+    /// are out of gamut
+    fn from(color: Oklab) -> Self {
+        let [red, green, blue] = color.to_srgb();
+        Self {
+            red: T::from_percent(red),
+            green: T::from_percent(green),
+            blue: T::from_percent(blue),
+            alpha: T::MAX,
+        }
+    }
+}
+
+impl<T> From<RGB<T>> for Oklch
+where
+    T: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl<T> From<RGBA<T>> for Oklch
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl<T> From<Oklch> for RGB<T>
+where
+    T: Channel,
+{
+    fn from(color: Oklch) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl<T> From<Oklch> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: Oklch) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    #[test]
+    fn primaries() {
+        let white: Oklab = RGB::<u8>::from(PrimaryColor::White).into();
+        assert!((white.lightness - 1.0).abs() < 1e-4);
+        assert!(white.a.abs() < 1e-4 && white.b.abs() < 1e-4);
+        let red: Oklch = RGB::<u8>::from(PrimaryColor::Red).into();
+        assert!((red.lightness - 0.628).abs() < 1e-3);
+        assert!((red.chroma - 0.2577).abs() < 1e-3);
+        assert!((red.hue - 29.23).abs() < 0.1);
+    }
+
+    #[test]
+    fn round_trip() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(15) {
+                for blue in (0..=255).step_by(15) {
+                    let color = RGBA::<u8>::new(red, green, blue, 255);
+                    assert_eq!(color, RGBA::from(Oklch::from(color)));
+                }
+            }
+        }
+    }
+}
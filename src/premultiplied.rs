@@ -0,0 +1,206 @@
+use {
+    crate::{channel, Channel, ColorError, RGBA},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Represents a color whose red, green and blue channels have already been
+/// multiplied by it's alpha channel, as expected by Cairo, Skia and most GPU
+/// APIs. Keeping these separate from `RGBA`, which always holds straight
+/// alpha, means the two can not be mixed up. No color channel is ever greater
+/// than the alpha channel.
+/// ```
+/// use rgba_simple::{PremultipliedRGBA, RGBA};
+///
+/// let red = RGBA::<u8>::new(255, 0, 0, 128);
+/// let premultiplied = red.premultiply();
+/// assert_eq!(premultiplied, PremultipliedRGBA::new(128, 0, 0, 128));
+/// assert_eq!(premultiplied.unpremultiply(), red);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PremultipliedRGBA<T>
+where
+    T: Channel,
+{
+    pub red: T,
+    pub green: T,
+    pub blue: T,
+    pub alpha: T,
+}
+
+impl<T> PremultipliedRGBA<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance from channels which are already premultiplied
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    /// Returns `ColorError::ExceedsAlpha` if any color channel is greater than the alpha channel
+    pub fn try_new(red: T, green: T, blue: T, alpha: T) -> Result<Self, ColorError> {
+        channel::validate(&[red, green, blue, alpha])?;
+        if [red, green, blue].iter().any(|c| *c > alpha) {
+            return Err(ColorError::ExceedsAlpha);
+        }
+        Ok(Self {
+            red,
+            green,
+            blue,
+            alpha,
+        })
+    }
+
+    /// Creates a new instance infallibly from channels which are already
+    /// premultiplied. Each channel is first clamped to the bounds
+    /// `ColorChannel::MIN` and `ColorChannel::MAX`, and any color channel which
+    /// is greater than the alpha channel is then reduced to the alpha value.
+    pub fn new(red: T, green: T, blue: T, alpha: T) -> Self {
+        let alpha = channel::clamp(alpha);
+        let limit = |c: T| {
+            let c = channel::clamp(c);
+            if c > alpha {
+                alpha
+            } else {
+                c
+            }
+        };
+        Self {
+            red: limit(red),
+            green: limit(green),
+            blue: limit(blue),
+            alpha,
+        }
+    }
+
+    /// Divides the color channels by the alpha channel, giving straight alpha.
+    /// A fully transparent color carries no color information, so it becomes
+    /// transparent black. Integer channels lose precision at low alpha values,
+    /// so a round trip through premultiplied alpha is not always exact.
+    #[must_use]
+    pub fn unpremultiply(&self) -> RGBA<T> {
+        if self.alpha <= T::MIN {
+            return RGBA {
+                red: T::MIN,
+                green: T::MIN,
+                blue: T::MIN,
+                alpha: T::MIN,
+            };
+        }
+        let alpha = self.alpha.to_percent_f64();
+        let divide = |c: T| T::from_percent_f64((c.to_percent_f64() / alpha).clamp(0.0, 1.0));
+        RGBA {
+            red: divide(self.red),
+            green: divide(self.green),
+            blue: divide(self.blue),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T> RGBA<T>
+where
+    T: Channel,
+{
+    /// Multiplies the color channels by the alpha channel
+    #[must_use]
+    pub fn premultiply(&self) -> PremultipliedRGBA<T> {
+        let alpha = self.alpha.to_percent_f64();
+        let multiply = |c: T| T::from_percent_f64(c.to_percent_f64() * alpha);
+        PremultipliedRGBA {
+            red: multiply(self.red),
+            green: multiply(self.green),
+            blue: multiply(self.blue),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<T> fmt::Display for PremultipliedRGBA<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PremultipliedRGBA({}, {}, {}, {})",
+            self.red.channel_display(),
+            self.green.channel_display(),
+            self.blue.channel_display(),
+            self.alpha.channel_display(),
+        )
+    }
+}
+
+impl<T> From<RGBA<T>> for PremultipliedRGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        color.premultiply()
+    }
+}
+
+impl<T> From<PremultipliedRGBA<T>> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: PremultipliedRGBA<T>) -> Self {
+        color.unpremultiply()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_alpha() {
+        let clear = RGBA::<u8>::new(255, 128, 0, 0).premultiply();
+        assert_eq!(clear, PremultipliedRGBA::new(0, 0, 0, 0));
+        assert_eq!(clear.unpremultiply(), RGBA::new(0, 0, 0, 0));
+        let clear = PremultipliedRGBA::<f32>::new(0.0, 0.0, 0.0, 0.0);
+        assert_eq!(clear.unpremultiply(), RGBA::new(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn exceeds_alpha() {
+        assert_eq!(
+            PremultipliedRGBA::<u8>::try_new(200, 0, 0, 100),
+            Err(ColorError::ExceedsAlpha)
+        );
+        assert_eq!(
+            PremultipliedRGBA::<f32>::new(0.8, 0.2, 1.5, 0.5),
+            PremultipliedRGBA::new(0.5, 0.2, 0.5, 0.5)
+        );
+    }
+
+    #[test]
+    fn deep_round_trip() {
+        for alpha in [1, 0x0001_0000, 0x8000_0000, u32::MAX - 1, u32::MAX] {
+            let color = RGBA::<u32>::new(u32::MAX, 0, 0, alpha);
+            let back = color.premultiply().unpremultiply();
+            assert_eq!(back, color);
+        }
+        let color = RGBA::<u32>::new(0x1234_5678, 0x9abc_def0, 7, u32::MAX);
+        assert_eq!(color.premultiply().unpremultiply(), color);
+    }
+
+    #[test]
+    fn round_trip() {
+        for alpha in (0..=255).step_by(15) {
+            for value in (0..=255).step_by(17) {
+                let color = RGBA::<u16>::new(value * 257, 0, 65535, alpha * 257);
+                let premultiplied = PremultipliedRGBA::from(color);
+                assert!(premultiplied.red <= premultiplied.alpha);
+                if alpha > 0 {
+                    let back = RGBA::from(premultiplied).convert::<u8>();
+                    assert_eq!(back, color.convert::<u8>());
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 #[allow(clippy::enum_glob_use)]
 use {
-    crate::{hex, Channel, ColorError, Hex, PrimaryColor, PrimaryColor::*},
+    crate::{channel, hex, Channel, ColorError, Hex, PrimaryColor, PrimaryColor::*, RGBA},
     std::fmt,
 };
 
@@ -29,13 +29,7 @@ where
     /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
     /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
     pub fn try_new(red: T, green: T, blue: T) -> Result<Self, ColorError> {
-        for c in [red, green, blue] {
-            if c < Channel::MIN {
-                return Err(ColorError::OutsideBoundsNegative);
-            } else if c > Channel::MAX {
-                return Err(ColorError::OutsideBoundsHigh);
-            }
-        }
+        channel::validate(&[red, green, blue])?;
         Ok(Self { red, green, blue })
     }
 
@@ -44,27 +38,27 @@ where
     /// will be either the minimum or the maximum, respectively.
     pub fn new(red: T, green: T, blue: T) -> Self {
         Self {
-            red: if red < Channel::MIN {
-                Channel::MIN
-            } else if red > Channel::MAX {
-                Channel::MAX
-            } else {
-                red
-            },
-            green: if green < Channel::MIN {
-                Channel::MIN
-            } else if green > Channel::MAX {
-                Channel::MAX
-            } else {
-                green
-            },
-            blue: if blue < Channel::MIN {
-                Channel::MIN
-            } else if blue > Channel::MAX {
-                Channel::MAX
-            } else {
-                blue
-            },
+            red: channel::clamp(red),
+            green: channel::clamp(green),
+            blue: channel::clamp(blue),
+        }
+    }
+
+    /// Converts the color to one with a different channel type, such as from
+    /// `RGB<u8>` to `RGB<f32>`. Conversions between integer types are exact,
+    /// rounding to the nearest value where the new type is less precise, so
+    /// widening and converting to the same type are always lossless. Other
+    /// conversions go through an `f64` percentage, which is lossless for every
+    /// channel type except `u64` and `i64`, whose lowest bits are rounded away
+    /// when converting to or from a floating point type.
+    pub fn convert<U>(&self) -> RGB<U>
+    where
+        U: Channel,
+    {
+        RGB {
+            red: channel::convert(self.red),
+            green: channel::convert(self.green),
+            blue: channel::convert(self.blue),
         }
     }
 }
@@ -106,6 +100,20 @@ where
     }
 }
 
+impl<T> From<RGBA<T>> for RGB<T>
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Self {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+        }
+    }
+}
+
 impl<T> Hex for RGB<T>
 where
     T: Channel + Hex,
@@ -121,27 +129,20 @@ where
         )
     }
 
+    fn to_hex_short(&self) -> String {
+        hex::shortest(&[self.red.to_hex(), self.green.to_hex(), self.blue.to_hex()])
+    }
+
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        hex::validate_hex_string(hex)?;
-        let red = match T::from_hex(&hex[1..3]) {
-            Ok(r) => r,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        let green = match T::from_hex(&hex[3..5]) {
-            Ok(g) => g,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
+        let channels = hex::split_channels(hex, false)?;
+        let (Ok(red), Ok(green), Ok(blue)) = (
+            T::from_hex(channels[0]),
+            T::from_hex(channels[1]),
+            T::from_hex(channels[2]),
+        ) else {
+            return Err(ColorError::InvalidHexCharacter);
         };
-        let blue = match T::from_hex(&hex[5..7]) {
-            Ok(b) => b,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        for channel in [red, green, blue] {
-            if channel < Channel::MIN {
-                return Err(ColorError::OutsideBoundsNegative);
-            } else if channel > Channel::MAX {
-                return Err(ColorError::OutsideBoundsHigh);
-            }
-        }
+        channel::validate(&[red, green, blue])?;
         Ok(Self { red, green, blue })
     }
 }
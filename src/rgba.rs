@@ -1,6 +1,6 @@
 #[allow(clippy::enum_glob_use)]
 use {
-    crate::{hex, Channel, ColorError, Hex, PrimaryColor, PrimaryColor::*},
+    crate::{channel, hex, Channel, ColorError, Hex, PrimaryColor, PrimaryColor::*, RGB},
     std::fmt,
 };
 
@@ -31,13 +31,7 @@ where
     /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
     /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
     pub fn try_new(red: T, green: T, blue: T, alpha: T) -> Result<Self, ColorError> {
-        for c in [red, green, blue, alpha] {
-            if c < Channel::MIN {
-                return Err(ColorError::OutsideBoundsNegative);
-            } else if c > Channel::MAX {
-                return Err(ColorError::OutsideBoundsHigh);
-            }
-        }
+        channel::validate(&[red, green, blue, alpha])?;
         Ok(Self {
             red,
             green,
@@ -51,34 +45,49 @@ where
     /// will be either the minimum or the maximum, respectively.
     pub fn new(red: T, green: T, blue: T, alpha: T) -> Self {
         Self {
-            red: if red < Channel::MIN {
-                Channel::MIN
-            } else if red > Channel::MAX {
-                Channel::MAX
-            } else {
-                red
-            },
-            green: if green < Channel::MIN {
-                Channel::MIN
-            } else if green > Channel::MAX {
-                Channel::MAX
-            } else {
-                green
-            },
-            blue: if blue < Channel::MIN {
-                Channel::MIN
-            } else if blue > Channel::MAX {
-                Channel::MAX
-            } else {
-                blue
-            },
-            alpha: if alpha < Channel::MIN {
-                Channel::MIN
-            } else if alpha > Channel::MAX {
-                Channel::MAX
-            } else {
-                alpha
-            },
+            red: channel::clamp(red),
+            green: channel::clamp(green),
+            blue: channel::clamp(blue),
+            alpha: channel::clamp(alpha),
+        }
+    }
+
+    /// The value of each channel as a percentage
+    pub(crate) fn percentages(&self) -> [f32; 4] {
+        [
+            self.red.to_percent(),
+            self.green.to_percent(),
+            self.blue.to_percent(),
+            self.alpha.to_percent(),
+        ]
+    }
+
+    /// Creates a color from the percentage value of each channel
+    pub(crate) fn from_percentages(channels: [f32; 4]) -> Self {
+        Self {
+            red: T::from_percent(channels[0]),
+            green: T::from_percent(channels[1]),
+            blue: T::from_percent(channels[2]),
+            alpha: T::from_percent(channels[3]),
+        }
+    }
+
+    /// Converts the color to one with a different channel type, such as from
+    /// `RGBA<u8>` to `RGBA<f32>`. Conversions between integer types are exact,
+    /// rounding to the nearest value where the new type is less precise, so
+    /// widening and converting to the same type are always lossless. Other
+    /// conversions go through an `f64` percentage, which is lossless for every
+    /// channel type except `u64` and `i64`, whose lowest bits are rounded away
+    /// when converting to or from a floating point type.
+    pub fn convert<U>(&self) -> RGBA<U>
+    where
+        U: Channel,
+    {
+        RGBA {
+            red: channel::convert(self.red),
+            green: channel::convert(self.green),
+            blue: channel::convert(self.blue),
+            alpha: channel::convert(self.alpha),
         }
     }
 }
@@ -122,47 +131,75 @@ where
     }
 }
 
+impl<T> From<RGB<T>> for RGBA<T>
+where
+    T: Channel,
+{
+    /// Converts to a fully opaque color
+    fn from(color: RGB<T>) -> Self {
+        Self {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+            alpha: T::MAX,
+        }
+    }
+}
+
 impl<T> Hex for RGBA<T>
 where
     T: Channel + Hex,
 {
     type Err = ColorError;
 
+    /// Represents the color as a hex string, including the alpha channel
+    /// only if the color is not fully opaque
     fn to_hex(&self) -> String {
-        format!(
-            "#{}{}{}",
-            self.red.to_hex(),
-            self.green.to_hex(),
-            self.blue.to_hex(),
-        )
+        if self.alpha == T::MAX {
+            format!(
+                "#{}{}{}",
+                self.red.to_hex(),
+                self.green.to_hex(),
+                self.blue.to_hex(),
+            )
+        } else {
+            format!(
+                "#{}{}{}{}",
+                self.red.to_hex(),
+                self.green.to_hex(),
+                self.blue.to_hex(),
+                self.alpha.to_hex(),
+            )
+        }
     }
 
+    fn to_hex_short(&self) -> String {
+        let mut channels = vec![self.red.to_hex(), self.green.to_hex(), self.blue.to_hex()];
+        if self.alpha != T::MAX {
+            channels.push(self.alpha.to_hex());
+        }
+        hex::shortest(&channels)
+    }
+
+    /// Converts a hex string with three or four channels, of one, two, four,
+    /// eight or sixteen digits each, to a color. If the alpha channel is
+    /// omitted the color will be fully opaque.
     fn from_hex(hex: &str) -> Result<Self, Self::Err> {
-        hex::validate_hex_string(hex)?;
-        let red = match T::from_hex(&hex[1..3]) {
-            Ok(r) => r,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        let green = match T::from_hex(&hex[3..5]) {
-            Ok(g) => g,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        let blue = match T::from_hex(&hex[5..7]) {
-            Ok(b) => b,
-            Err(_) => return Err(ColorError::InvalidHexCharacter),
-        };
-        for channel in [red, green, blue] {
-            if channel < Channel::MIN {
-                return Err(ColorError::OutsideBoundsNegative);
-            } else if channel > Channel::MAX {
-                return Err(ColorError::OutsideBoundsHigh);
-            }
+        let channels = hex::split_channels(hex, true)?;
+        let mut values = Vec::with_capacity(4);
+        for c in &channels {
+            let Ok(value) = T::from_hex(c) else {
+                return Err(ColorError::InvalidHexCharacter);
+            };
+            values.push(value);
         }
+        values.resize(4, T::MAX);
+        channel::validate(&values)?;
         Ok(Self {
-            red,
-            green,
-            blue,
-            alpha: Channel::MAX,
+            red: values[0],
+            green: values[1],
+            blue: values[2],
+            alpha: values[3],
         })
     }
 }
@@ -0,0 +1,337 @@
+use crate::{
+    linear,
+    matrix::{self, Matrix},
+    xyz::{LINEAR_SRGB_TO_XYZ, XYZ_TO_LINEAR_SRGB},
+    Channel, WhitePoint, Xyz, RGBA,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// An RGB color space, defined by the XYZ coordinates of it's primaries, it's
+/// white point and the transfer function used to gamma encode it's values.
+/// The marker types implementing this trait are used to tag colors with the
+/// space they belong to, in `TaggedRGBA`.
+pub trait RgbColorSpace: Copy + Default {
+    /// The reference white of the color space
+    const WHITE_POINT: WhitePoint;
+    /// The matrix converting linear light values in this space to XYZ,
+    /// relative to `WHITE_POINT`
+    const TO_XYZ: [[f32; 3]; 3];
+    /// The inverse of `TO_XYZ`, converting XYZ relative to `WHITE_POINT` to
+    /// linear light values in this space
+    const FROM_XYZ: [[f32; 3]; 3];
+    /// The name of the space as used by the CSS `color()` function
+    const CSS_NAME: &'static str;
+
+    /// Converts a gamma encoded value to linear light
+    fn decode(value: f32) -> f32;
+
+    /// Converts a linear light value to it's gamma encoded form
+    fn encode(value: f32) -> f32;
+}
+
+/// The standard color space of the web and most displays, which the crate
+/// assumes for an untagged `RGBA`
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Srgb;
+
+/// The wide gamut space of Apple displays, with the primaries of DCI-P3 and
+/// the white point and transfer function of sRGB
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct DisplayP3;
+
+/// The very wide gamut space of ITU-R BT.2020, used for ultra high definition
+/// video
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Rec2020;
+
+/// Adobe RGB (1998), a wide gamut space used in photography and print
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct AdobeRgb;
+
+/// The `ProPhoto` RGB space, also known as ROMM RGB, a very wide gamut space
+/// relative to the D50 white point which is used for editing photographs
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct ProPhotoRgb;
+
+impl RgbColorSpace for Srgb {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const TO_XYZ: Matrix = LINEAR_SRGB_TO_XYZ;
+    const FROM_XYZ: Matrix = XYZ_TO_LINEAR_SRGB;
+    const CSS_NAME: &'static str = "srgb";
+
+    fn decode(value: f32) -> f32 {
+        linear::srgb_decode(value)
+    }
+
+    fn encode(value: f32) -> f32 {
+        linear::srgb_encode(value)
+    }
+}
+
+impl RgbColorSpace for DisplayP3 {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const TO_XYZ: Matrix = [
+        [0.486_570_95, 0.265_667_7, 0.198_217_29],
+        [0.228_974_57, 0.691_738_55, 0.079_286_91],
+        [0.0, 0.045_113_38, 1.043_944_4],
+    ];
+    const FROM_XYZ: Matrix = [
+        [2.493_497, -0.931_383_6, -0.402_710_8],
+        [-0.829_488_93, 1.762_664, 0.023_624_698],
+        [0.035_845_827, -0.076_172_38, 0.956_884_5],
+    ];
+    const CSS_NAME: &'static str = "display-p3";
+
+    fn decode(value: f32) -> f32 {
+        linear::srgb_decode(value)
+    }
+
+    fn encode(value: f32) -> f32 {
+        linear::srgb_encode(value)
+    }
+}
+
+const REC2020_ALPHA: f32 = 1.099_296_8;
+const REC2020_BETA: f32 = 0.018_053_97;
+
+impl RgbColorSpace for Rec2020 {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const TO_XYZ: Matrix = [
+        [0.636_958_06, 0.144_616_9, 0.168_880_97],
+        [0.262_700_2, 0.677_998_07, 0.059_301_715],
+        [0.0, 0.028_072_692, 1.060_985_1],
+    ];
+    const FROM_XYZ: Matrix = [
+        [1.716_651_2, -0.355_670_78, -0.253_366_26],
+        [-0.666_684_3, 1.616_481_2, 0.015_768_537],
+        [0.017_639_855, -0.042_770_61, 0.942_103_1],
+    ];
+    const CSS_NAME: &'static str = "rec2020";
+
+    fn decode(value: f32) -> f32 {
+        if value.abs() < REC2020_BETA * 4.5 {
+            value / 4.5
+        } else {
+            value.signum() * ((value.abs() + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+        }
+    }
+
+    fn encode(value: f32) -> f32 {
+        if value.abs() < REC2020_BETA {
+            value * 4.5
+        } else {
+            value.signum() * (REC2020_ALPHA * value.abs().powf(0.45) - (REC2020_ALPHA - 1.0))
+        }
+    }
+}
+
+impl RgbColorSpace for AdobeRgb {
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+    const TO_XYZ: Matrix = [
+        [0.576_669_04, 0.185_558_24, 0.188_228_65],
+        [0.297_344_98, 0.627_363_56, 0.075_291_455],
+        [0.027_031_362, 0.070_688_85, 0.991_337_54],
+    ];
+    const FROM_XYZ: Matrix = [
+        [2.041_587_8, -0.565_007, -0.344_731_36],
+        [-0.969_243_65, 1.875_967_5, 0.041_555_073],
+        [0.013_444_278, -0.118_362_39, 1.015_175],
+    ];
+    const CSS_NAME: &'static str = "a98-rgb";
+
+    fn decode(value: f32) -> f32 {
+        value.signum() * value.abs().powf(563.0 / 256.0)
+    }
+
+    fn encode(value: f32) -> f32 {
+        value.signum() * value.abs().powf(256.0 / 563.0)
+    }
+}
+
+impl RgbColorSpace for ProPhotoRgb {
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+    const TO_XYZ: Matrix = [
+        [0.797_766_6, 0.135_181_3, 0.031_347_733],
+        [0.288_074_82, 0.711_835_2, 0.000_089_936_94],
+        [0.0, 0.0, 0.825_104_6],
+    ];
+    const FROM_XYZ: Matrix = [
+        [1.345_786_9, -0.255_572_1, -0.051_101_867],
+        [-0.544_630_77, 1.508_247_9, 0.020_527_449],
+        [0.0, 0.0, 1.211_967_6],
+    ];
+    const CSS_NAME: &'static str = "prophoto-rgb";
+
+    fn decode(value: f32) -> f32 {
+        if value.abs() <= 16.0 / 512.0 {
+            value / 16.0
+        } else {
+            value.signum() * value.abs().powf(1.8)
+        }
+    }
+
+    fn encode(value: f32) -> f32 {
+        if value.abs() < 1.0 / 512.0 {
+            value * 16.0
+        } else {
+            value.signum() * value.abs().powf(1.0 / 1.8)
+        }
+    }
+}
+
+/// Converts gamma encoded values in a color space to XYZ relative to D65
+pub(crate) fn to_xyz<S>(rgb: [f32; 3]) -> Xyz
+where
+    S: RgbColorSpace,
+{
+    Xyz::from(matrix::transform(&S::TO_XYZ, rgb.map(S::decode)))
+        .adapt(S::WHITE_POINT, WhitePoint::D65)
+}
+
+/// Converts XYZ relative to D65 to gamma encoded values in a color space,
+/// without clipping to it's gamut
+pub(crate) fn from_xyz<S>(xyz: Xyz) -> [f32; 3]
+where
+    S: RgbColorSpace,
+{
+    let xyz = xyz.adapt(WhitePoint::D65, S::WHITE_POINT);
+    matrix::transform(&S::FROM_XYZ, xyz.into()).map(S::encode)
+}
+
+/// An `RGBA` color tagged with the color space it's channels are in. Untagged
+/// colors are assumed to be sRGB by the rest of the crate.
+/// ```
+/// use rgba_simple::{DisplayP3, Srgb, TaggedRGBA, RGBA};
+///
+/// let red = TaggedRGBA::<u8, Srgb>::new(RGBA::new(255, 0, 0, 255));
+/// let p3 = red.convert_space::<DisplayP3>();
+/// assert_eq!(p3.color, RGBA::new(234, 51, 35, 255));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct TaggedRGBA<T, S>
+where
+    T: Channel,
+    S: RgbColorSpace,
+{
+    pub color: RGBA<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub space: S,
+}
+
+impl<T, S> TaggedRGBA<T, S>
+where
+    T: Channel,
+    S: RgbColorSpace,
+{
+    /// Tags a color as belonging to the color space `S`
+    pub fn new(color: RGBA<T>) -> Self {
+        Self {
+            color,
+            space: S::default(),
+        }
+    }
+
+    /// Converts the color to XYZ relative to D65, discarding the alpha channel
+    #[must_use]
+    pub fn to_xyz(&self) -> Xyz {
+        let [red, green, blue, _] = self.color.percentages();
+        to_xyz::<S>([red, green, blue])
+    }
+
+    /// Converts the color to another color space through XYZ, using the
+    /// Bradford transform where the white points differ. Channels which are
+    /// outside of the gamut of the new space are clipped.
+    #[must_use]
+    pub fn convert_space<D>(&self) -> TaggedRGBA<T, D>
+    where
+        D: RgbColorSpace,
+    {
+        let [red, green, blue] = from_xyz::<D>(self.to_xyz());
+        TaggedRGBA::new(RGBA::from_percentages([
+            red.clamp(0.0, 1.0),
+            green.clamp(0.0, 1.0),
+            blue.clamp(0.0, 1.0),
+            self.color.alpha.to_percent(),
+        ]))
+    }
+}
+
+impl<T> From<RGBA<T>> for TaggedRGBA<T, Srgb>
+where
+    T: Channel,
+{
+    fn from(color: RGBA<T>) -> Self {
+        Self::new(color)
+    }
+}
+
+impl<T> From<TaggedRGBA<T, Srgb>> for RGBA<T>
+where
+    T: Channel,
+{
+    fn from(color: TaggedRGBA<T, Srgb>) -> Self {
+        color.color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_gamut() {
+        let p3_red = TaggedRGBA::<u8, DisplayP3>::new(RGBA::new(255, 0, 0, 255));
+        let clipped: RGBA<u8> = p3_red.convert_space::<Srgb>().into();
+        assert_eq!(clipped, RGBA::new(255, 0, 0, 255));
+        let srgb = from_xyz::<Srgb>(p3_red.to_xyz());
+        assert!((srgb[0] - 1.093).abs() < 1e-3 && srgb[1] < 0.0 && srgb[2] < 0.0);
+    }
+
+    fn round_trip<S: RgbColorSpace>() {
+        for red in (0..=255).step_by(51) {
+            for green in (0..=255).step_by(51) {
+                for blue in (0..=255).step_by(51) {
+                    let color = RGBA::<u8>::new(red, green, blue, 128);
+                    let tagged = TaggedRGBA::<f32, Srgb>::new(color.convert());
+                    let back = tagged.convert_space::<S>().convert_space::<Srgb>();
+                    assert_eq!(back.color.convert::<u8>(), color);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_matrices() {
+        fn check<S: RgbColorSpace>() {
+            let identity = matrix::multiply(&S::FROM_XYZ, &S::TO_XYZ);
+            for (i, row) in identity.iter().enumerate() {
+                for (j, cell) in row.iter().enumerate() {
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!((cell - expected).abs() < 1e-5);
+                }
+            }
+        }
+        check::<Srgb>();
+        check::<DisplayP3>();
+        check::<Rec2020>();
+        check::<AdobeRgb>();
+        check::<ProPhotoRgb>();
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip::<Srgb>();
+        round_trip::<DisplayP3>();
+        round_trip::<Rec2020>();
+        round_trip::<AdobeRgb>();
+        round_trip::<ProPhotoRgb>();
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{linear, Channel, Xyz, RGB};
+
+/// The lowest temperature in kelvin covered by the Planckian locus
+/// approximation
+const MIN_KELVIN: f32 = 1667.0;
+/// The highest temperature in kelvin covered by the Planckian locus
+/// approximation
+const MAX_KELVIN: f32 = 25000.0;
+/// The range in kelvin over which the `McCamy` approximation is trusted
+const CCT_RANGE: std::ops::RangeInclusive<f32> = 2000.0..=12500.0;
+
+/// The CIE 1931 xy chromaticity of a black body radiator, using the cubic
+/// spline approximation of Kim et al.
+fn planckian_locus(kelvin: f32) -> (f32, f32) {
+    let t = if kelvin.is_finite() {
+        kelvin.clamp(MIN_KELVIN, MAX_KELVIN)
+    } else if kelvin > 0.0 {
+        MAX_KELVIN
+    } else {
+        MIN_KELVIN
+    };
+    let (t2, t3) = (t * t, t * t * t);
+    let x = if t <= 4000.0 {
+        -0.266_123_9e9 / t3 - 0.234_358_9e6 / t2 + 0.877_695_6e3 / t + 0.179_910
+    } else {
+        -3.025_846_9e9 / t3 + 2.107_038e6 / t2 + 0.222_634_7e3 / t + 0.240_390
+    };
+    let (x2, x3) = (x * x, x * x * x);
+    let y = if t <= 2222.0 {
+        -1.106_381_4 * x3 - 1.348_110_2 * x2 + 2.185_558_3 * x - 0.202_196_83
+    } else if t <= 4000.0 {
+        -0.954_947_6 * x3 - 1.374_185_9 * x2 + 2.091_37 * x - 0.167_488_67
+    } else {
+        3.081_758 * x3 - 5.873_387 * x2 + 3.751_13 * x - 0.370_014_83
+    };
+    (x, y)
+}
+
+impl<T> RGB<T>
+where
+    T: Channel,
+{
+    /// Creates the brightest sRGB color with the chromaticity of a black body
+    /// radiator at the given temperature in kelvin. Temperatures are clamped
+    /// to the range 1667 K to 25000 K, with NaN treated as the lowest, and the
+    /// few channels which fall outside of the sRGB gamut at very low
+    /// temperatures are clipped.
+    /// ```
+    /// use rgba_simple::RGB;
+    ///
+    /// let candle = RGB::<u8>::from_kelvin(1900.0);
+    /// assert!(candle.red > candle.green && candle.green > candle.blue);
+    /// let daylight = RGB::<u8>::from_kelvin(6500.0);
+    /// assert!(daylight.green > 240 && daylight.blue > 240);
+    /// ```
+    #[must_use]
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        let (x, y) = planckian_locus(kelvin);
+        let rgb = Xyz {
+            x: x / y,
+            y: 1.0,
+            z: (1.0 - x - y) / y,
+        }
+        .to_linear_srgb()
+        .map(|c| c.max(0.0));
+        let max = rgb[0].max(rgb[1]).max(rgb[2]);
+        let [red, green, blue] = rgb.map(|c| linear::srgb_encode(c / max));
+        Self {
+            red: T::from_percent(red),
+            green: T::from_percent(green),
+            blue: T::from_percent(blue),
+        }
+    }
+
+    /// Estimates the correlated color temperature in kelvin of this color
+    /// using the cubic approximation of `McCamy`, which is accurate to within
+    /// a few kelvin between 2856 K and 6504 K and degrades further from the
+    /// Planckian locus. Returns `None` for black, which has no chromaticity,
+    /// and for colors whose estimate falls outside of 2000 K to 12500 K, such
+    /// as saturated blues, where the approximation is meaningless.
+    /// ```
+    /// use rgba_simple::RGB;
+    ///
+    /// let white = RGB::<u8>::new(255, 255, 255);
+    /// let cct = white.correlated_color_temperature().unwrap();
+    /// assert!((cct - 6504.0).abs() < 10.0);
+    /// assert!(RGB::<u8>::new(0, 0, 255).correlated_color_temperature().is_none());
+    /// ```
+    #[must_use]
+    pub fn correlated_color_temperature(&self) -> Option<f32> {
+        let xyz = Xyz::from(*self);
+        let sum = xyz.x + xyz.y + xyz.z;
+        if sum <= 0.0 {
+            return None;
+        }
+        let (x, y) = (xyz.x / sum, xyz.y / sum);
+        let denominator = 0.1858 - y;
+        if denominator.abs() < 1e-6 {
+            return None;
+        }
+        let n = (x - 0.3320) / denominator;
+        let cct = 449.0 * n.powi(3) + 3525.0 * n.powi(2) + 6823.3 * n + 5520.33;
+        CCT_RANGE.contains(&cct).then_some(cct)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for kelvin in [2500.0, 3000.0, 4000.0, 5000.0, 6500.0, 8000.0] {
+            let color = RGB::<f32>::from_kelvin(kelvin);
+            let cct = color.correlated_color_temperature().unwrap();
+            assert!((cct - kelvin).abs() / kelvin < 0.01);
+        }
+    }
+
+    #[test]
+    fn warm_and_cool() {
+        let warm = RGB::<u8>::from_kelvin(2700.0);
+        assert_eq!(warm.red, 255);
+        assert!(warm.blue < warm.green);
+        let cool = RGB::<u8>::from_kelvin(10000.0);
+        assert_eq!(cool.blue, 255);
+        assert!(cool.red < cool.green);
+        assert_eq!(RGB::<u8>::from_kelvin(0.0), RGB::from_kelvin(MIN_KELVIN));
+        assert_eq!(
+            RGB::<u8>::from_kelvin(f32::NAN),
+            RGB::from_kelvin(MIN_KELVIN)
+        );
+        assert_eq!(
+            RGB::<u8>::from_kelvin(f32::INFINITY),
+            RGB::from_kelvin(MAX_KELVIN)
+        );
+        assert!(RGB::<u8>::new(0, 0, 0)
+            .correlated_color_temperature()
+            .is_none());
+    }
+
+    #[test]
+    fn out_of_range() {
+        for color in [
+            RGB::<u8>::new(0, 0, 255),
+            RGB::new(64, 0, 255),
+            RGB::new(0, 255, 255),
+            RGB::new(128, 128, 255),
+        ] {
+            assert!(color.correlated_color_temperature().is_none());
+        }
+        for kelvin in [2100.0, 12000.0] {
+            let color = RGB::<f32>::from_kelvin(kelvin);
+            assert!(color.correlated_color_temperature().is_some());
+        }
+        assert!(RGB::<f32>::from_kelvin(1900.0)
+            .correlated_color_temperature()
+            .is_none());
+    }
+}
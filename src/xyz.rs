@@ -0,0 +1,202 @@
+use {
+    crate::{
+        chromatic_adaptation::{self, Cat},
+        linear,
+        matrix::{self, Matrix},
+        Channel, RGB, RGBA,
+    },
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Standard illuminants used as the reference white of a color space, for the
+/// CIE 1931 2° standard observer
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum WhitePoint {
+    /// Incandescent tungsten light
+    A,
+    /// Average daylight, superseded by the D series
+    C,
+    /// Horizon light, used as the reference white for printing
+    D50,
+    /// Mid morning or mid afternoon daylight, used in photography
+    D55,
+    /// Noon daylight, the reference white of sRGB and most displays
+    #[default]
+    D65,
+    /// North sky daylight
+    D75,
+    /// The equal energy illuminant, the reference white of XYZ itself
+    E,
+    /// Cool white fluorescent light
+    F2,
+    /// Broadband fluorescent light simulating D65
+    F7,
+    /// Narrow band fluorescent light
+    F11,
+}
+
+impl WhitePoint {
+    /// The CIE 1931 xy chromaticity coordinates of this white point
+    #[must_use]
+    pub fn chromaticity(self) -> (f32, f32) {
+        match self {
+            Self::A => (0.447_57, 0.407_45),
+            Self::C => (0.310_06, 0.316_16),
+            Self::D50 => (0.3457, 0.3585),
+            Self::D55 => (0.332_42, 0.347_43),
+            Self::D65 => (0.3127, 0.3290),
+            Self::D75 => (0.299_02, 0.314_85),
+            Self::E => (1.0 / 3.0, 1.0 / 3.0),
+            Self::F2 => (0.372_08, 0.375_29),
+            Self::F7 => (0.312_92, 0.329_33),
+            Self::F11 => (0.380_52, 0.377_13),
+        }
+    }
+
+    /// The tristimulus values of this white point, normalized so that `y` is 1.0
+    #[must_use]
+    pub fn xyz(self) -> Xyz {
+        let (x, y) = self.chromaticity();
+        Xyz {
+            x: x / y,
+            y: 1.0,
+            z: (1.0 - x - y) / y,
+        }
+    }
+}
+
+/// Represents a color in the CIE 1931 XYZ color space, where `y` is the
+/// relative luminance in the range 0.0 to 1.0. Colors converted from `RGB`
+/// are relative to the D65 white point.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+pub(crate) const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_65, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+pub(crate) const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.24097, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+impl Xyz {
+    /// Adapts a color seen under one white point to how it would appear under
+    /// another, using the Bradford transform
+    #[must_use]
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint) -> Self {
+        self.adapt_with(from, to, Cat::Bradford)
+    }
+
+    /// Adapts a color seen under one white point to how it would appear under
+    /// another, using the given chromatic adaptation transform
+    #[must_use]
+    pub fn adapt_with(&self, from: WhitePoint, to: WhitePoint, cat: Cat) -> Self {
+        chromatic_adaptation::adapt(*self, from, to, cat)
+    }
+
+    /// Converts a color in linear sRGB, relative to D65, to XYZ
+    pub(crate) fn from_linear_srgb(rgb: [f32; 3]) -> Self {
+        matrix::transform(&LINEAR_SRGB_TO_XYZ, rgb).into()
+    }
+
+    /// Converts to linear sRGB without clipping to the sRGB gamut
+    pub(crate) fn to_linear_srgb(self) -> [f32; 3] {
+        matrix::transform(&XYZ_TO_LINEAR_SRGB, self.into())
+    }
+
+    /// Converts to gamma encoded sRGB percentages, clipped to the sRGB gamut
+    pub(crate) fn to_srgb(self) -> [f32; 3] {
+        self.to_linear_srgb()
+            .map(|c| linear::srgb_encode(c).clamp(0.0, 1.0))
+    }
+}
+
+impl From<[f32; 3]> for Xyz {
+    fn from(xyz: [f32; 3]) -> Self {
+        Self {
+            x: xyz[0],
+            y: xyz[1],
+            z: xyz[2],
+        }
+    }
+}
+
+impl From<Xyz> for [f32; 3] {
+    fn from(xyz: Xyz) -> Self {
+        [xyz.x, xyz.y, xyz.z]
+    }
+}
+
+impl fmt::Display for Xyz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "XYZ({:.3}, {:.3}, {:.3})", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<RGB<T>> for Xyz
+where
+    T: Channel,
+{
+    fn from(color: RGB<T>) -> Self {
+        Self::from_linear_srgb(
+            [color.red, color.green, color.blue].map(|c| linear::srgb_decode(c.to_percent())),
+        )
+    }
+}
+
+impl<T> From<RGBA<T>> for Xyz
+where
+    T: Channel,
+{
+    /// Converts the color channels, discarding the alpha channel
+    fn from(color: RGBA<T>) -> Self {
+        Self::from_linear_srgb(
+            [color.red, color.green, color.blue].map(|c| linear::srgb_decode(c.to_percent())),
+        )
+    }
+}
+
+impl<T> From<Xyz> for RGB<T>
+where
+    T: Channel,
+{
+    /// Converts to sRGB, clipping any channels which are out of gamut
+    fn from(color: Xyz) -> Self {
+        let [red, green, blue] = color.to_srgb();
+        Self {
+            red: T::from_percent(red),
+            green: T::from_percent(green),
+            blue: T::from_percent(blue),
+        }
+    }
+}
+
+impl<T> From<Xyz> for RGBA<T>
+where
+    T: Channel,
+{
+    /// Converts to a fully opaque sRGB color, clipping any channels which
+    /// are out of gamut
+    fn from(color: Xyz) -> Self {
+        let [red, green, blue] = color.to_srgb();
+        Self {
+            red: T::from_percent(red),
+            green: T::from_percent(green),
+            blue: T::from_percent(blue),
+            alpha: T::MAX,
+        }
+    }
+}
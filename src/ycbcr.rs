@@ -0,0 +1,271 @@
+use {
+    crate::{channel, Channel, ColorError, RGB},
+    std::fmt,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The standards defining how luma and chroma are derived from gamma encoded
+/// red, green and blue
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum MatrixCoefficients {
+    /// ITU-R BT.601, used for standard definition video and JPEG
+    Bt601,
+    /// ITU-R BT.709, used for high definition video
+    #[default]
+    Bt709,
+    /// ITU-R BT.2020, used for ultra high definition video
+    Bt2020,
+}
+
+impl MatrixCoefficients {
+    /// The weights of red and blue in the luma
+    fn kr_kb(self) -> (f32, f32) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+            Self::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+}
+
+/// The range of values used to encode a `YCbCr` color
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum QuantizationRange {
+    /// Every value of the channel is used, as in JPEG
+    #[default]
+    Full,
+    /// The studio range used by most video, where in 8 bit terms luma ranges
+    /// from 16 to 235 and chroma from 16 to 240. Deeper integer channels scale
+    /// these values by their bit depth, so that 16 bit luma ranges from 4096
+    /// to 60160 and the 15 bits of an `i16` from 2048 to 30080, while floating
+    /// point channels use the 8 bit fractions.
+    Limited,
+}
+
+impl QuantizationRange {
+    /// The offset and scale of the luma and chroma as fractions of the range
+    /// of the channel type
+    fn scale<T>(self) -> ((f32, f32), (f32, f32))
+    where
+        T: Channel,
+    {
+        match self {
+            Self::Full => ((0.0, 1.0), (0.0, 1.0)),
+            Self::Limited => {
+                let bits = T::MAX
+                    .to_integer()
+                    .map_or(8, |max| (max + 1).trailing_zeros());
+                let step = (f64::from(bits) - 8.0).exp2() / (f64::from(bits).exp2() - 1.0);
+                let fraction = |value: f64| (value * step) as f32;
+                (
+                    (fraction(16.0), fraction(219.0)),
+                    (fraction(16.0), fraction(224.0)),
+                )
+            }
+        }
+    }
+}
+
+/// Represents a color as luma and blue and red difference chroma, as used for
+/// digital video. The chroma channels are centered on the middle of their
+/// range, so that no color difference is represented by `(Channel::MAX + 1) / 2`
+/// for integer channels, and by 0.5 for floating point channels in the full
+/// range or 128 / 255 in the limited range.
+/// ```
+/// use rgba_simple::{MatrixCoefficients, QuantizationRange, YCbCr, RGB};
+///
+/// let gray = RGB::<u8>::new(128, 128, 128);
+/// let full = YCbCr::<u16>::from_rgb(gray, MatrixCoefficients::Bt709, QuantizationRange::Full);
+/// assert_eq!((full.cb, full.cr), (32768, 32768));
+/// let limited = YCbCr::<i16>::from_rgb(gray, MatrixCoefficients::Bt709, QuantizationRange::Limited);
+/// assert_eq!((limited.cb, limited.cr), (16384, 16384));
+/// let float = YCbCr::<f32>::from_rgb(gray, MatrixCoefficients::Bt709, QuantizationRange::Full);
+/// assert_eq!((float.cb, float.cr), (0.5, 0.5));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct YCbCr<T>
+where
+    T: Channel,
+{
+    pub y: T,
+    pub cb: T,
+    pub cr: T,
+}
+
+impl<T> YCbCr<T>
+where
+    T: Channel,
+{
+    /// Creates a new instance
+    ///
+    /// # Errors
+    /// Returns `ColorError::OutsideBoundsNegative` if any channel is less than `ColorChannel::MIN`
+    /// Returns `ColorError::OutsideBoundsHigh` if any channel is greater than `ColorChannel::MAX`
+    pub fn try_new(y: T, cb: T, cr: T) -> Result<Self, ColorError> {
+        channel::validate(&[y, cb, cr])?;
+        Ok(Self { y, cb, cr })
+    }
+
+    /// Creates a new instance infallibly. If any of the arguments are outside of
+    /// the bounds `ColorChannel::MIN` and `ColorChannel::MAX`, that channels value
+    /// will be either the minimum or the maximum, respectively.
+    pub fn new(y: T, cb: T, cr: T) -> Self {
+        Self {
+            y: channel::clamp(y),
+            cb: channel::clamp(cb),
+            cr: channel::clamp(cr),
+        }
+    }
+
+    /// Converts from `RGB` using the given matrix coefficients and range
+    /// ```
+    /// use rgba_simple::{MatrixCoefficients, QuantizationRange, YCbCr, RGB};
+    ///
+    /// let red = RGB::<u8>::new(255, 0, 0);
+    /// let video = YCbCr::<u8>::from_rgb(red, MatrixCoefficients::Bt709, QuantizationRange::Limited);
+    /// assert_eq!(video, YCbCr::new(63, 102, 240));
+    /// ```
+    pub fn from_rgb<U>(color: RGB<U>, matrix: MatrixCoefficients, range: QuantizationRange) -> Self
+    where
+        U: Channel,
+    {
+        let (kr, kb) = matrix.kr_kb();
+        let [red, green, blue] = [color.red, color.green, color.blue].map(|c| c.to_percent());
+        let y = kr * red + (1.0 - kr - kb) * green + kb * blue;
+        let cb = (blue - y) / (2.0 * (1.0 - kb)) + 0.5;
+        let cr = (red - y) / (2.0 * (1.0 - kr)) + 0.5;
+        let ((y_offset, y_scale), (c_offset, c_scale)) = range.scale::<T>();
+        Self {
+            y: T::from_percent(y_offset + y * y_scale),
+            cb: T::from_percent(c_offset + cb * c_scale),
+            cr: T::from_percent(c_offset + cr * c_scale),
+        }
+    }
+
+    /// Converts to `RGB` using the given matrix coefficients and range,
+    /// clipping any channels which are out of gamut
+    #[must_use]
+    pub fn to_rgb<U>(&self, matrix: MatrixCoefficients, range: QuantizationRange) -> RGB<U>
+    where
+        U: Channel,
+    {
+        let (kr, kb) = matrix.kr_kb();
+        let ((y_offset, y_scale), (c_offset, c_scale)) = range.scale::<T>();
+        let y = (self.y.to_percent() - y_offset) / y_scale;
+        let cb = (self.cb.to_percent() - c_offset) / c_scale - 0.5;
+        let cr = (self.cr.to_percent() - c_offset) / c_scale - 0.5;
+        let red = y + 2.0 * (1.0 - kr) * cr;
+        let blue = y + 2.0 * (1.0 - kb) * cb;
+        let green = (y - kr * red - kb * blue) / (1.0 - kr - kb);
+        RGB {
+            red: U::from_percent(red.clamp(0.0, 1.0)),
+            green: U::from_percent(green.clamp(0.0, 1.0)),
+            blue: U::from_percent(blue.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Converts a slice of `RGB` colors, such as the pixels of an image
+    pub fn from_rgb_slice<U>(
+        colors: &[RGB<U>],
+        matrix: MatrixCoefficients,
+        range: QuantizationRange,
+    ) -> Vec<Self>
+    where
+        U: Channel,
+    {
+        colors
+            .iter()
+            .map(|c| Self::from_rgb(*c, matrix, range))
+            .collect()
+    }
+
+    /// Converts a slice of colors to `RGB`, such as the pixels of a decoded
+    /// video frame
+    pub fn to_rgb_slice<U>(
+        colors: &[Self],
+        matrix: MatrixCoefficients,
+        range: QuantizationRange,
+    ) -> Vec<RGB<U>>
+    where
+        U: Channel,
+    {
+        colors.iter().map(|c| c.to_rgb(matrix, range)).collect()
+    }
+}
+
+impl<T> fmt::Display for YCbCr<T>
+where
+    T: Channel,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "YCbCr({}, {}, {})",
+            self.y.channel_display(),
+            self.cb.channel_display(),
+            self.cr.channel_display(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimaryColor;
+
+    #[test]
+    fn primaries() {
+        let white = RGB::<u8>::from(PrimaryColor::White);
+        let full = YCbCr::<u8>::from_rgb(white, MatrixCoefficients::Bt601, QuantizationRange::Full);
+        assert_eq!(full, YCbCr::new(255, 128, 128));
+        let red = RGB::<u8>::from(PrimaryColor::Red);
+        let limited =
+            YCbCr::<u8>::from_rgb(red, MatrixCoefficients::Bt601, QuantizationRange::Limited);
+        assert_eq!(limited, YCbCr::new(81, 90, 240));
+        let black = YCbCr::<u8>::new(16, 128, 128);
+        assert_eq!(
+            black.to_rgb::<u8>(MatrixCoefficients::Bt2020, QuantizationRange::Limited),
+            PrimaryColor::Black.into()
+        );
+    }
+
+    #[test]
+    fn deep_limited_range() {
+        let range = QuantizationRange::Limited;
+        let black = RGB::<u16>::from(PrimaryColor::Black);
+        let white = RGB::<u16>::from(PrimaryColor::White);
+        let video = YCbCr::<u16>::from_rgb(black, MatrixCoefficients::Bt709, range);
+        assert_eq!(video, YCbCr::new(4096, 32768, 32768));
+        let video = YCbCr::<u16>::from_rgb(white, MatrixCoefficients::Bt709, range);
+        assert_eq!(video, YCbCr::new(60160, 32768, 32768));
+        assert_eq!(video.to_rgb::<u16>(MatrixCoefficients::Bt709, range), white);
+        let video = YCbCr::<i16>::from_rgb(black, MatrixCoefficients::Bt709, range);
+        assert_eq!(video, YCbCr::new(2048, 16384, 16384));
+        let video = YCbCr::<i16>::from_rgb(white, MatrixCoefficients::Bt709, range);
+        assert_eq!(video, YCbCr::new(30080, 16384, 16384));
+        assert_eq!(video.to_rgb::<u16>(MatrixCoefficients::Bt709, range), white);
+    }
+
+    #[test]
+    fn round_trip() {
+        let colors: Vec<RGB<u8>> = (0..=255)
+            .step_by(15)
+            .flat_map(|r| (0..=255).step_by(51).map(move |g| RGB::new(r, g, 255 - r)))
+            .collect();
+        for matrix in [
+            MatrixCoefficients::Bt601,
+            MatrixCoefficients::Bt709,
+            MatrixCoefficients::Bt2020,
+        ] {
+            for range in [QuantizationRange::Full, QuantizationRange::Limited] {
+                let ycbcr = YCbCr::<f32>::from_rgb_slice(&colors, matrix, range);
+                assert_eq!(YCbCr::to_rgb_slice::<u8>(&ycbcr, matrix, range), colors);
+            }
+        }
+    }
+}